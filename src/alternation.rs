@@ -0,0 +1,387 @@
+//! Creates alternations, i.e. patterns matching one of a few branches.
+//!
+//! Unlike the [`or`](../struct.EasyRegex.html#method.or) method which splits the whole chain at the point it is called,
+//! the [`alternation`](../struct.EasyRegex.html#method.alternation) and [`one_of`](../struct.EasyRegex.html#method.one_of) methods
+//! create a closed alternation which could be put anywhere in a chain.
+//! It is wrapped into a non-capturing group automatically when other expressions come before or after it,
+//! or when a quantifier is set for it.
+//! To match one of many words, [`one_of_words`](../struct.EasyRegex.html#method.one_of_words) factors their common prefixes out,
+//! making patterns such as ```Jan(?:uary)?``` instead of ```Jan|January```.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    ast::{self, GroupKind, Node},
+    settings::{Flags, Quantifier, Settings},
+    EasyRegex, EasyRegexError,
+};
+
+impl EasyRegex {
+    /// Creates an alternation out of ```EasyRegex``` sections.
+    ///
+    /// Flags set at the top level of a section are kept inside its own branch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::base::{DEFAULT, ONE_OR_MORE}};
+    ///
+    /// let number = EasyRegex::new_section().digit(&ONE_OR_MORE);
+    /// let word = EasyRegex::new_section().word(&ONE_OR_MORE);
+    ///
+    /// let result = EasyRegex::new_section()
+    ///     .literal("id=", &DEFAULT)
+    ///     .alternation([number, word], &DEFAULT);
+    /// assert_eq!(r"id=(?:\d+|\w+)", result.get_regex().unwrap().as_str());
+    /// ```
+    pub fn alternation<I>(self, sections: I, settings: &Settings) -> Self
+    where
+        I: IntoIterator<Item = EasyRegex>,
+    {
+        let branches = sections
+            .into_iter()
+            .map(|section| branch(section.0))
+            .collect();
+        self.push(alternation_nodes("alternation", branches, settings))
+    }
+
+    /// Creates an alternation out of expressions, inserted as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::base::{DEFAULT, OPTIONAL}};
+    ///
+    /// let result = EasyRegex::new_section()
+    ///     .literal("image.", &DEFAULT)
+    ///     .one_of(&["jpe?g", "png", "gif"], &DEFAULT);
+    /// assert_eq!(r"image\.(?:jpe?g|png|gif)", result.get_regex().unwrap().as_str());
+    ///
+    /// let result = EasyRegex::new_section().one_of(&["Mr", "Mrs"], &OPTIONAL);
+    /// assert_eq!("(?:Mr|Mrs)?", result.get_regex().unwrap().as_str());
+    /// ```
+    pub fn one_of(self, expressions: &[&str], settings: &Settings) -> Self {
+        let branches = expressions
+            .iter()
+            .map(|expression| {
+                vec![Node::Raw {
+                    expression: expression.to_string(),
+                    method: "one_of",
+                }]
+            })
+            .collect();
+        self.push(alternation_nodes("one_of", branches, settings))
+    }
+
+    /// Creates an alternation matching one of the given words literally.
+    ///
+    /// Words are escaped and merged into a trie, so common prefixes are matched once
+    /// and words with the same prefix are tried from the longest one.
+    /// Duplicates are removed, and if the ```Flags::Insensitive``` flag is in the settings,
+    /// words differing only in case are matched by a single branch, as far as the simple case folding
+    /// of the regex crate goes (e.g. ```İ``` and ```i``` are kept apart).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::{Settings, base::DEFAULT}};
+    ///
+    /// let result = EasyRegex::new_section().one_of_words(&["Jan", "January", "Feb", "February"], &DEFAULT);
+    /// assert_eq!("Feb(?:ruary)?|Jan(?:uary)?", result.get_regex().unwrap().as_str());
+    ///
+    /// let whole_words = Settings {
+    ///     with_left_boundary: true,
+    ///     with_right_boundary: true,
+    ///     ..Default::default()
+    /// };
+    /// let result = EasyRegex::new_section().one_of_words(&["GET", "HEAD", "PUT", "POST"], &whole_words);
+    /// assert_eq!(r"\b(?:GET|HEAD|P(?:OST|UT))\b", result.get_regex().unwrap().as_str());
+    /// ```
+    pub fn one_of_words(self, words: &[&str], settings: &Settings) -> Self {
+        let is_insensitive = settings
+            .flags
+            .is_some_and(|flags| flags.contains(Flags::Insensitive));
+        let mut trie = Trie::default();
+        for word in words {
+            if is_insensitive {
+                trie.insert(&word.chars().map(fold_simple).collect::<String>());
+            } else {
+                trie.insert(word);
+            }
+        }
+
+        let node = match trie.rest() {
+            _ if words.is_empty() => Node::Choice(Vec::new()),
+            mut nodes if nodes.len() == 1 => nodes.remove(0),
+            nodes => Node::Choice(vec![nodes]),
+        };
+        let mut nodes = settings.apply("one_of_words", node);
+        if words.is_empty() {
+            nodes.push(Node::Invalid(EasyRegexError::EmptyAlternation {
+                method: "one_of_words",
+            }));
+        }
+        self.push(nodes)
+    }
+}
+
+/// Lowercases a letter the way the regex crate folds its case, i.e. by simple case folding only,
+/// so letters such as ```İ``` whose lowercase is longer than one character are left as they are.
+fn fold_simple(letter: char) -> char {
+    use regex_syntax::hir::{ClassUnicode, ClassUnicodeRange};
+
+    let mut lowercase = letter.to_lowercase();
+    let (Some(lower), None) = (lowercase.next(), lowercase.next()) else {
+        return letter;
+    };
+    let mut class = ClassUnicode::new([ClassUnicodeRange::new(letter, letter)]);
+    class.case_fold_simple();
+    let is_folded = class
+        .ranges()
+        .iter()
+        .any(|range| range.start() <= lower && lower <= range.end());
+    if is_folded {
+        lower
+    } else {
+        letter
+    }
+}
+
+/// Words sharing their prefixes, used by the ```one_of_words``` method.
+#[derive(Default)]
+struct Trie {
+    children: BTreeMap<char, Trie>,
+    is_word_end: bool,
+}
+
+impl Trie {
+    fn insert(&mut self, word: &str) {
+        let end = word
+            .chars()
+            .fold(self, |node, letter| node.children.entry(letter).or_default());
+        end.is_word_end = true;
+    }
+
+    /// Nodes matching the rest of any word passing through this node.
+    fn rest(&self) -> Vec<Node> {
+        let mut branches = self.branches();
+        let mut nodes = match branches.len() {
+            0 => return Vec::new(),
+            1 => branches.remove(0),
+            _ => vec![Node::Choice(branches)],
+        };
+        if !self.is_word_end {
+            return nodes;
+        }
+
+        let node = if nodes.len() == 1 && nodes[0].is_atom() {
+            nodes.remove(0)
+        } else {
+            Node::Group {
+                kind: GroupKind::NonCapturing,
+                nodes,
+            }
+        };
+        vec![Node::Repetition {
+            node: Box::new(node),
+            quantifier: Quantifier::Optional,
+        }]
+    }
+
+    /// One branch for each child, except for last letters of words which are put into a single list.
+    fn branches(&self) -> Vec<Vec<Node>> {
+        let mut last_letters = String::new();
+        let mut branches = Vec::new();
+        for (letter, child) in &self.children {
+            if child.children.is_empty() {
+                last_letters.push(*letter);
+                continue;
+            }
+            let mut branch = child.rest();
+            match branch.first_mut() {
+                Some(Node::Literal(text)) => text.insert(0, *letter),
+                _ => branch.insert(0, Node::Literal(letter.to_string())),
+            }
+            branches.push(branch);
+        }
+
+        match last_letters.chars().count() {
+            0 => {}
+            1 => branches.push(vec![Node::Literal(last_letters)]),
+            _ => branches.push(vec![Node::Class {
+                negated: false,
+                items: vec![Node::Literal(last_letters)],
+                method: "one_of_words",
+            }]),
+        }
+        branches
+    }
+}
+
+/// Keeps top-level flags and alternations of a section inside its own branch.
+fn branch(nodes: Vec<Node>) -> Vec<Node> {
+    if ast::is_self_contained(&nodes) {
+        nodes
+    } else {
+        vec![Node::Group {
+            kind: GroupKind::NonCapturing,
+            nodes,
+        }]
+    }
+}
+
+fn alternation_nodes(
+    method: &'static str,
+    branches: Vec<Vec<Node>>,
+    settings: &Settings,
+) -> Vec<Node> {
+    let is_empty = branches.is_empty();
+    let mut nodes = settings.apply(method, Node::Choice(branches));
+    if is_empty {
+        nodes.push(Node::Invalid(EasyRegexError::EmptyAlternation { method }));
+    }
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{base::*, group::DEFAULT_GROUP, FlagSet, Flags};
+
+    #[test]
+    fn alternation_works() {
+        let result = EasyRegex::new_section().alternation(
+            [
+                EasyRegex::new_section().literal("a.b", &DEFAULT),
+                EasyRegex::insensitive().literal("c", &DEFAULT),
+                EasyRegex::new("d").or().raw("e", &DEFAULT),
+            ],
+            &DEFAULT,
+        );
+        assert_eq!("a\\.b|(?:(?i)c)|(?:d|e)", result.as_str());
+    }
+
+    #[test]
+    fn alternation_followed_by_more_works() {
+        let result = EasyRegex::new_section()
+            .one_of(&["cat", "dog"], &DEFAULT)
+            .literal("s", &OPTIONAL);
+        assert_eq!("(?:cat|dog)s?", result.as_str());
+        assert!(result.get_regex().unwrap().is_match("dogs"));
+
+        let result = EasyRegex::start_of_line()
+            .one_of(&["a", "b"], &Settings::exactly(2))
+            .end_of_line();
+        assert_eq!("^(?:a|b){2}$", result.as_str());
+    }
+
+    #[test]
+    fn alternation_with_settings_works() {
+        let result = EasyRegex::new_section().one_of(
+            &["yes", "no"],
+            &Settings {
+                flags: Some(Flags::Insensitive.into()),
+                with_left_boundary: true,
+                with_right_boundary: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!("\\b(?i)(?:yes|no)\\b", result.as_str());
+
+        let result = EasyRegex::new_section()
+            .one_of(&["yes", "no"], &DEFAULT)
+            .into_group(&DEFAULT);
+        assert_eq!("(yes|no)", result.as_str());
+
+        let result = EasyRegex::new_section()
+            .group("x", &DEFAULT_GROUP)
+            .one_of(&["y", "z"], &DEFAULT)
+            .or()
+            .raw("w", &DEFAULT);
+        assert_eq!("(x)(?:y|z)|w", result.as_str());
+    }
+
+    #[test]
+    fn one_of_words_works() {
+        let result = EasyRegex::new_section().one_of_words(
+            &["car", "cars", "cart", "cat", "dog", "do", "dog", "a.b", "a-c"],
+            &DEFAULT,
+        );
+        assert_eq!("a(?:\\-c|\\.b)|ca(?:r[st]?|t)|dog?", result.as_str());
+
+        let months = [
+            "January", "Jan", "February", "Feb", "March", "Mar", "April", "Apr", "May", "June",
+            "Jun", "July", "Jul", "August", "Aug", "September", "Sep", "Sept", "October", "Oct",
+            "November", "Nov", "December", "Dec",
+        ];
+        let result = EasyRegex::new_section().one_of_words(&months, &DEFAULT);
+        let regex = result.get_regex().unwrap();
+        for month in ["Jan", "January", "Sept", "September", "May", "Dec"] {
+            assert_eq!(Some(month), regex.find(month).map(|found| found.as_str()));
+        }
+
+        let words = ["interval", "internal", "internet", "interview", "interface"];
+        let naive = EasyRegex::new_section().one_of(&words, &DEFAULT);
+        let result = EasyRegex::new_section().one_of_words(&words, &DEFAULT);
+        assert_eq!("inter(?:face|n(?:al|et)|v(?:al|iew))", result.as_str());
+        assert!(result.as_str().len() < naive.as_str().len());
+    }
+
+    #[test]
+    fn one_of_words_with_settings_works() {
+        let result = EasyRegex::new_section()
+            .literal("(", &DEFAULT)
+            .one_of_words(
+                &["Yes", "yes", "YES", "no"],
+                &Settings {
+                    flags: Some(Flags::Insensitive.into()),
+                    with_left_boundary: true,
+                    with_right_boundary: true,
+                    ..Default::default()
+                },
+            );
+        assert_eq!("\\(\\b(?i)(?:no|yes)\\b", result.as_str());
+
+        let insensitive = FlagSet::from(Flags::Insensitive).into();
+        let result = EasyRegex::new_section()
+            .one_of_words(&["İstanbul", "istanbul", "ÉTÉ", "été"], &insensitive);
+        assert_eq!("(?i)(?:istanbul|été|İstanbul)", result.as_str());
+        let regex = result.get_regex().unwrap();
+        for word in ["İSTANBUL", "istanbul", "ISTANBUL", "Été"] {
+            assert!(regex.is_match(word), "{}", word);
+        }
+
+        let result = EasyRegex::new_section().one_of_words(&["ab", "abc"], &ONE_OR_MORE);
+        assert_eq!("(?:abc?)+", result.as_str());
+
+        let result = EasyRegex::new_section().one_of_words(&["", "x", "y"], &DEFAULT);
+        assert_eq!("[xy]?", result.as_str());
+
+        let result = EasyRegex::new_section().one_of_words(&[], &DEFAULT);
+        assert_eq!(
+            EasyRegexError::EmptyAlternation {
+                method: "one_of_words"
+            },
+            result.get_regex().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn alternation_into_list_fails() {
+        let result = EasyRegex::new_section()
+            .one_of(&["a", "b"], &DEFAULT)
+            .into_list(&DEFAULT);
+        assert_eq!(
+            "into_list: an alternation cannot be turned into a list, its '|' would be matched literally",
+            result.get_regex().unwrap_err().to_string()
+        );
+
+        let result = EasyRegex::new_section().alternation(Vec::new(), &DEFAULT);
+        assert_eq!(
+            EasyRegexError::EmptyAlternation {
+                method: "alternation"
+            },
+            result.get_regex().unwrap_err()
+        );
+    }
+}
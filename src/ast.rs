@@ -0,0 +1,681 @@
+//! Internal pattern tree built up by the chaining methods.
+//!
+//! Every method of [`EasyRegex`](crate::EasyRegex) appends one or more ```Node```s instead of concatenating text,
+//! so later steps of a chain know exactly what they are wrapping.
+//! The tree is rendered into a plain string only when the regex is requested.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::{
+    engine::Construct,
+    error::EasyRegexError,
+    settings::{FlagSet, Flags, Quantifier},
+};
+
+/// A single building block of a pattern.
+#[derive(Debug, Clone)]
+pub(crate) enum Node {
+    /// Pattern text inserted verbatim, keeping the method which added it.
+    Raw {
+        expression: String,
+        method: &'static str,
+    },
+    /// Text matched literally, its metacharacters are escaped when rendered.
+    Literal(String),
+    /// A bracketed list such as ```[abc]``` or ```[^abc]```, keeping the method which added it.
+    Class {
+        negated: bool,
+        items: Vec<Node>,
+        method: &'static str,
+    },
+    /// A capturing, named or non-capturing group.
+    Group { kind: GroupKind, nodes: Vec<Node> },
+    /// Branches separated by ```|```, left open by the ```or``` method so that following nodes continue its last branch.
+    Alternation(Vec<Vec<Node>>),
+    /// A closed alternation, wrapped into a non-capturing group when it has more than one branch
+    /// and is not the whole sequence.
+    Choice(Vec<Vec<Node>>),
+    /// A node followed by a quantifier.
+    Repetition {
+        node: Box<Node>,
+        quantifier: Quantifier,
+    },
+    /// A zero-width assertion such as ```^``` or ```\b```.
+    Assertion(Assertion),
+    /// A flag applied to the rest of the enclosing group, e.g. ```(?i)```.
+    Flags(FlagSet),
+    /// A flag applied to the wrapped nodes only, e.g. ```(?i:...)```.
+    FlagScope { flags: FlagSet, nodes: Vec<Node> },
+    /// A reference to the text matched by a group, given by its index or name, only supported by fancy-regex.
+    #[cfg(feature = "fancy-regex")]
+    Backreference(String),
+    /// A zero-width assertion that the wrapped nodes match ahead of or behind the current position, only supported by fancy-regex.
+    #[cfg(feature = "fancy-regex")]
+    Lookaround { kind: Lookaround, nodes: Vec<Node> },
+    /// A byte or a range of bytes matched with Unicode disabled, e.g. ```(?-u:\xFF)```, keeping the method which added it.
+    Byte {
+        from: u8,
+        to: u8,
+        method: &'static str,
+    },
+    /// A misuse found while building the pattern, rendered as nothing and reported when the regex is requested.
+    Invalid(EasyRegexError),
+}
+
+/// Kind of a group, capturing ones keep the name of the method which created them.
+#[derive(Debug, Clone)]
+pub(crate) enum GroupKind {
+    Capturing {
+        method: &'static str,
+    },
+    Named {
+        name: String,
+        method: &'static str,
+    },
+    NonCapturing,
+}
+
+/// Kind of a lookaround, along with the method which creates it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Lookaround {
+    Ahead,
+    NegativeAhead,
+    Behind,
+    NegativeBehind,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Assertion {
+    StartOfLine,
+    EndOfLine,
+    OnlyTheBeginning,
+    OnlyTheEnd,
+    WordBoundary,
+    NonWordBoundary,
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Node::Raw { expression, .. } => f.write_str(expression),
+            Node::Literal(text) => f.write_str(&regex::escape(text)),
+            Node::Class { negated, items, .. } => {
+                f.write_str(if *negated { "[^" } else { "[" })?;
+                write_nodes(f, items)?;
+                f.write_str("]")
+            }
+            Node::Group { kind, nodes } => {
+                match kind {
+                    GroupKind::Capturing { .. } => f.write_str("(")?,
+                    GroupKind::Named { name, .. } => write!(f, "(?P<{}>", name)?,
+                    GroupKind::NonCapturing => f.write_str("(?:")?,
+                }
+                write_nodes(f, nodes)?;
+                f.write_str(")")
+            }
+            Node::Alternation(branches) | Node::Choice(branches) => {
+                for (index, branch) in branches.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str("|")?;
+                    }
+                    write_nodes(f, branch)?;
+                }
+                Ok(())
+            }
+            Node::Repetition { node, quantifier } => write!(f, "{}{}", node, quantifier),
+            Node::Assertion(assertion) => f.write_str(assertion.as_str()),
+            Node::Flags(flags) => write!(f, "({})", flags),
+            Node::FlagScope { flags, nodes } => {
+                write!(f, "({}:", flags)?;
+                write_nodes(f, nodes)?;
+                f.write_str(")")
+            }
+            #[cfg(feature = "fancy-regex")]
+            Node::Backreference(group) => write!(f, "\\k<{}>", group),
+            #[cfg(feature = "fancy-regex")]
+            Node::Lookaround { kind, nodes } => {
+                f.write_str(kind.as_str())?;
+                write_nodes(f, nodes)?;
+                f.write_str(")")
+            }
+            Node::Byte { from, to, .. } if from == to => write!(f, "(?-u:\\x{:02X})", from),
+            Node::Byte { from, to, .. } => write!(f, "(?-u:[\\x{:02X}-\\x{:02X}])", from, to),
+            Node::Invalid(_) => Ok(()),
+        }
+    }
+}
+
+impl Node {
+    /// Checks if a quantifier appended to the node repeats all of it, e.g. ```\d```, ```[a-z]``` or a single character.
+    pub(crate) fn is_atom(&self) -> bool {
+        match self {
+            Node::Raw { expression, .. } => is_atom_pattern(expression),
+            Node::Literal(text) => text.chars().count() == 1,
+            Node::Class { .. } | Node::Group { .. } | Node::FlagScope { .. } => true,
+            Node::Assertion(_) | Node::Byte { .. } => true,
+            #[cfg(feature = "fancy-regex")]
+            Node::Backreference(_) | Node::Lookaround { .. } => true,
+            Node::Alternation(_) | Node::Choice(_) => false,
+            Node::Repetition { .. } | Node::Flags(_) => false,
+            Node::Invalid(_) => false,
+        }
+    }
+}
+
+/// Wraps the repeated nodes which aren't atoms into a non-capturing group, nested sequences included.
+pub(crate) fn wrap_quantified(nodes: &mut [Node]) {
+    for node in nodes {
+        match node {
+            Node::Repetition { node, .. } => {
+                wrap_quantified(std::slice::from_mut(node.as_mut()));
+                if !node.is_atom() {
+                    let inner = std::mem::replace(node.as_mut(), Node::Literal(String::new()));
+                    **node = Node::Group {
+                        kind: GroupKind::NonCapturing,
+                        nodes: vec![inner],
+                    };
+                }
+            }
+            Node::Group { nodes, .. } | Node::FlagScope { nodes, .. } => wrap_quantified(nodes),
+            #[cfg(feature = "fancy-regex")]
+            Node::Lookaround { nodes, .. } => wrap_quantified(nodes),
+            Node::Alternation(branches) | Node::Choice(branches) => branches
+                .iter_mut()
+                .for_each(|branch| wrap_quantified(branch)),
+            _ => {}
+        }
+    }
+}
+
+/// Parses a raw pattern to see if it is a single atom, unparsable patterns are left as they are.
+fn is_atom_pattern(raw: &str) -> bool {
+    use regex_syntax::ast::{parse::Parser, Ast};
+
+    match Parser::new().parse(raw) {
+        Ok(
+            Ast::Empty(_)
+            | Ast::Flags(_)
+            | Ast::Repetition(_)
+            | Ast::Alternation(_)
+            | Ast::Concat(_),
+        ) => false,
+        Ok(_) => true,
+        Err(_) => true,
+    }
+}
+
+impl Lookaround {
+    #[cfg(feature = "fancy-regex")]
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Lookaround::Ahead => "(?=",
+            Lookaround::NegativeAhead => "(?!",
+            Lookaround::Behind => "(?<=",
+            Lookaround::NegativeBehind => "(?<!",
+        }
+    }
+
+    pub(crate) fn method(&self) -> &'static str {
+        match self {
+            Lookaround::Ahead => "followed_by",
+            Lookaround::NegativeAhead => "not_followed_by",
+            Lookaround::Behind => "preceded_by",
+            Lookaround::NegativeBehind => "not_preceded_by",
+        }
+    }
+}
+
+impl Assertion {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Assertion::StartOfLine => "^",
+            Assertion::EndOfLine => "$",
+            Assertion::OnlyTheBeginning => "\\A",
+            Assertion::OnlyTheEnd => "\\z",
+            Assertion::WordBoundary => "\\b",
+            Assertion::NonWordBoundary => "\\B",
+        }
+    }
+}
+
+fn write_nodes(f: &mut Formatter<'_>, nodes: &[Node]) -> fmt::Result {
+    nodes.iter().try_for_each(|node| match node {
+        Node::Choice(branches) if nodes.len() > 1 && branches.len() > 1 => {
+            write!(f, "(?:{})", node)
+        }
+        _ => write!(f, "{}", node),
+    })
+}
+
+/// Renders a sequence of nodes into the final pattern text.
+pub(crate) fn render(nodes: &[Node]) -> String {
+    struct Sequence<'a>(&'a [Node]);
+
+    impl Display for Sequence<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write_nodes(f, self.0)
+        }
+    }
+
+    Sequence(nodes).to_string()
+}
+
+/// Finds the first misuse recorded in a sequence of nodes.
+pub(crate) fn find_error(nodes: &[Node]) -> Option<&EasyRegexError> {
+    nodes.iter().find_map(|node| match node {
+        Node::Invalid(error) => Some(error),
+        Node::Class { items: nodes, .. }
+        | Node::Group { nodes, .. }
+        | Node::FlagScope { nodes, .. } => find_error(nodes),
+        #[cfg(feature = "fancy-regex")]
+        Node::Lookaround { nodes, .. } => find_error(nodes),
+        Node::Alternation(branches) | Node::Choice(branches) => {
+            branches.iter().find_map(|branch| find_error(branch))
+        }
+        Node::Repetition { node, .. } => find_error(std::slice::from_ref(node.as_ref())),
+        Node::Raw { .. } | Node::Literal(_) | Node::Assertion(_) | Node::Flags(_) => None,
+        Node::Byte { .. } => None,
+        #[cfg(feature = "fancy-regex")]
+        Node::Backreference(_) => None,
+    })
+}
+
+/// Finds the name of the first method which added a construct an engine doesn't support.
+pub(crate) fn find_unsupported(
+    nodes: &[Node],
+    supports: fn(Construct) -> bool,
+) -> Option<&'static str> {
+    nodes.iter().find_map(|node| match node {
+        Node::Byte { to, method, .. } if *to > 0x7F && !supports(Construct::NonAsciiByte) => {
+            Some(*method)
+        }
+        #[cfg(feature = "fancy-regex")]
+        Node::Backreference(_) if !supports(Construct::Backreference) => Some("backreference"),
+        #[cfg(feature = "fancy-regex")]
+        Node::Lookaround { kind, .. } if !supports(Construct::Lookaround) => Some(kind.method()),
+        #[cfg(feature = "fancy-regex")]
+        Node::Lookaround { nodes, .. } => find_unsupported(nodes, supports),
+        Node::Group { nodes, .. } | Node::FlagScope { nodes, .. } => {
+            find_unsupported(nodes, supports)
+        }
+        Node::Alternation(branches) | Node::Choice(branches) => branches
+            .iter()
+            .find_map(|branch| find_unsupported(branch, supports)),
+        Node::Repetition { node, .. } => {
+            find_unsupported(std::slice::from_ref(node.as_ref()), supports)
+        }
+        _ => None,
+    })
+}
+
+/// Collects the groups referred to by backreferences, as the index or name written between ```\k<``` and ```>```.
+#[cfg(feature = "fancy-regex")]
+pub(crate) fn backreferences<'a>(nodes: &'a [Node], references: &mut Vec<&'a str>) {
+    for node in nodes {
+        match node {
+            Node::Backreference(group) => references.push(group),
+            Node::Group { nodes, .. }
+            | Node::FlagScope { nodes, .. }
+            | Node::Lookaround { nodes, .. } => backreferences(nodes, references),
+            Node::Alternation(branches) | Node::Choice(branches) => branches
+                .iter()
+                .for_each(|branch| backreferences(branch, references)),
+            Node::Repetition { node, .. } => {
+                backreferences(std::slice::from_ref(node.as_ref()), references)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Finds the first raw expression or class needing Unicode where it is disabled, e.g. ```\p{Greek}``` under ```(?-u)```,
+/// along with the method which added it.
+///
+/// Flags are followed as the regex crate applies them, starting with Unicode enabled or not.
+pub(crate) fn find_unicode_only(nodes: &[Node], unicode: bool) -> Option<(&'static str, String)> {
+    let mut unicode = unicode;
+    scan_unicode_only(nodes, &mut unicode)
+}
+
+/// Same as ```find_unicode_only```, leaving the state of the Unicode flag at the end of the sequence,
+/// since flags set in a branch of an alternation still apply to the following branches.
+fn scan_unicode_only(nodes: &[Node], unicode: &mut bool) -> Option<(&'static str, String)> {
+    for node in nodes {
+        let found = match node {
+            Node::Flags(flags) => {
+                *unicode = unicode_enabled(flags, *unicode);
+                None
+            }
+            Node::FlagScope { flags, nodes } => {
+                find_unicode_only(nodes, unicode_enabled(flags, *unicode))
+            }
+            Node::Group { nodes, .. } => find_unicode_only(nodes, *unicode),
+            #[cfg(feature = "fancy-regex")]
+            Node::Lookaround { nodes, .. } => find_unicode_only(nodes, *unicode),
+            Node::Alternation(branches) | Node::Choice(branches) => branches
+                .iter()
+                .find_map(|branch| scan_unicode_only(branch, unicode)),
+            Node::Repetition { node, .. } => {
+                find_unicode_only(std::slice::from_ref(node.as_ref()), *unicode)
+            }
+            Node::Raw { method, .. } | Node::Class { method, .. } if !*unicode => {
+                let expression = node.to_string();
+                needs_unicode(&expression).then_some((*method, expression))
+            }
+            _ => None,
+        };
+        if found.is_some() {
+            return found;
+        }
+    }
+    None
+}
+
+fn unicode_enabled(flags: &FlagSet, unicode: bool) -> bool {
+    if flags.contains(Flags::Unicode) {
+        true
+    } else if flags.contains(Flags::NoUnicode) {
+        false
+    } else {
+        unicode
+    }
+}
+
+/// Checks if an expression is valid with Unicode enabled only, expressions invalid either way are left to the regex crate.
+fn needs_unicode(expression: &str) -> bool {
+    use regex_syntax::ParserBuilder;
+
+    let without_unicode = ParserBuilder::new()
+        .unicode(false)
+        .utf8(false)
+        .build()
+        .parse(expression);
+    without_unicode.is_err() && regex_syntax::parse(expression).is_ok()
+}
+
+/// Prefix of the names given to the groups created by methods while parsing the whole pattern.
+const GROUP_MARK: &str = "__easy_regex_group_";
+
+/// Collects the capture groups of a sequence in the order the regex crate numbers them,
+/// as pairs of their optional name and the method creating them.
+///
+/// The whole pattern is parsed at once, so groups split across raw expressions (e.g. ```(``` and ```a)```) are counted too,
+/// with the groups created by methods renamed to be told apart. Groups written in raw expressions have no method.
+/// If the pattern can't be parsed, only the groups of raw expressions which can be parsed on their own are listed.
+pub(crate) fn captures(nodes: &[Node], groups: &mut Vec<(Option<String>, Option<&'static str>)>) {
+    let mut marked = nodes.to_vec();
+    let mut created = Vec::new();
+    mark_groups(&mut marked, &mut created);
+    if !parse_captures(&render(&marked), &created, groups) {
+        tree_captures(nodes, groups);
+    }
+}
+
+/// Renames the capture groups created by methods after their position, keeping their original name and method.
+fn mark_groups(nodes: &mut [Node], created: &mut Vec<(Option<String>, &'static str)>) {
+    for node in nodes {
+        match node {
+            Node::Group { kind, nodes } => {
+                let original = match kind {
+                    GroupKind::Capturing { method } => Some((None, *method)),
+                    GroupKind::Named { name, method } => Some((Some(name.clone()), *method)),
+                    GroupKind::NonCapturing => None,
+                };
+                if let Some((name, method)) = original {
+                    *kind = GroupKind::Named {
+                        name: format!("{}{}", GROUP_MARK, created.len()),
+                        method,
+                    };
+                    created.push((name, method));
+                }
+                mark_groups(nodes, created);
+            }
+            Node::FlagScope { nodes, .. } => mark_groups(nodes, created),
+            #[cfg(feature = "fancy-regex")]
+            Node::Lookaround { nodes, .. } => mark_groups(nodes, created),
+            Node::Alternation(branches) | Node::Choice(branches) => branches
+                .iter_mut()
+                .for_each(|branch| mark_groups(branch, created)),
+            Node::Repetition { node, .. } => {
+                mark_groups(std::slice::from_mut(node.as_mut()), created)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn tree_captures(nodes: &[Node], groups: &mut Vec<(Option<String>, Option<&'static str>)>) {
+    for node in nodes {
+        match node {
+            Node::Raw { expression, .. } => {
+                parse_captures(expression, &[], groups);
+            }
+            Node::Group { kind, nodes } => {
+                match kind {
+                    GroupKind::Capturing { method } => groups.push((None, Some(*method))),
+                    GroupKind::Named { name, method } => {
+                        groups.push((Some(name.clone()), Some(*method)))
+                    }
+                    GroupKind::NonCapturing => {}
+                }
+                tree_captures(nodes, groups);
+            }
+            Node::FlagScope { nodes, .. } => tree_captures(nodes, groups),
+            #[cfg(feature = "fancy-regex")]
+            Node::Lookaround { nodes, .. } => tree_captures(nodes, groups),
+            Node::Alternation(branches) | Node::Choice(branches) => branches
+                .iter()
+                .for_each(|branch| tree_captures(branch, groups)),
+            Node::Repetition { node, .. } => {
+                tree_captures(std::slice::from_ref(node.as_ref()), groups)
+            }
+            Node::Literal(_) | Node::Class { .. } | Node::Assertion(_) | Node::Flags(_) => {}
+            Node::Byte { .. } => {}
+            #[cfg(feature = "fancy-regex")]
+            Node::Backreference(_) => {}
+            Node::Invalid(_) => {}
+        }
+    }
+}
+
+/// Parses a pattern and collects its capture groups, those named by ```mark_groups``` are replaced by the created ones.
+///
+/// Returns false if the pattern can't be parsed.
+fn parse_captures(
+    pattern: &str,
+    created: &[(Option<String>, &'static str)],
+    groups: &mut Vec<(Option<String>, Option<&'static str>)>,
+) -> bool {
+    use regex_syntax::ast::{parse::Parser, visit, Ast, GroupKind as AstGroupKind, Visitor};
+
+    struct Collector<'a> {
+        created: &'a [(Option<String>, &'static str)],
+        groups: &'a mut Vec<(Option<String>, Option<&'static str>)>,
+    }
+
+    impl Visitor for Collector<'_> {
+        type Output = ();
+        type Err = ();
+
+        fn finish(self) -> Result<(), ()> {
+            Ok(())
+        }
+
+        fn visit_pre(&mut self, ast: &Ast) -> Result<(), ()> {
+            if let Ast::Group(group) = ast {
+                match &group.kind {
+                    AstGroupKind::CaptureIndex(_) => self.groups.push((None, None)),
+                    AstGroupKind::CaptureName { name, .. } => {
+                        let created = name
+                            .name
+                            .strip_prefix(GROUP_MARK)
+                            .and_then(|position| position.parse::<usize>().ok())
+                            .and_then(|position| self.created.get(position));
+                        match created {
+                            Some((name, method)) => self.groups.push((name.clone(), Some(*method))),
+                            None => self.groups.push((Some(name.name.clone()), None)),
+                        }
+                    }
+                    AstGroupKind::NonCapturing(_) => {}
+                }
+            }
+            Ok(())
+        }
+    }
+
+    match Parser::new().parse(pattern) {
+        Ok(ast) => visit(&ast, Collector { created, groups }).is_ok(),
+        Err(_) => false,
+    }
+}
+
+/// Checks if a sequence could be put after other nodes without changing its meaning or theirs,
+/// i.e. it has neither a top-level alternation nor top-level flags leaking into what follows.
+pub(crate) fn is_self_contained(nodes: &[Node]) -> bool {
+    nodes
+        .iter()
+        .all(|node| !matches!(node, Node::Alternation(_) | Node::Flags(_)))
+}
+
+/// Wraps a sequence into a non-capturing group unless it is self-contained.
+pub(crate) fn enclose(nodes: Vec<Node>) -> Vec<Node> {
+    if is_self_contained(&nodes) {
+        nodes
+    } else {
+        vec![Node::Group {
+            kind: GroupKind::NonCapturing,
+            nodes,
+        }]
+    }
+}
+
+/// Merges the flags of a sequence made of flags only, e.g. the one started by ```EasyRegex::insensitive```.
+pub(crate) fn only_flags(nodes: &[Node]) -> Option<FlagSet> {
+    if nodes.is_empty() {
+        return None;
+    }
+    nodes
+        .iter()
+        .try_fold(FlagSet::new(), |merged, node| match node {
+            Node::Flags(flags) => Some(merged | *flags),
+            _ => None,
+        })
+}
+
+/// Checks if a sequence has an alternation at its top level.
+pub(crate) fn has_alternation(nodes: &[Node]) -> bool {
+    nodes
+        .iter()
+        .any(|node| matches!(node, Node::Alternation(_) | Node::Choice(_)))
+}
+
+/// Appends nodes to a sequence, continuing the last branch if the sequence ends in an open alternation.
+pub(crate) fn append(sequence: &mut Vec<Node>, nodes: Vec<Node>) {
+    if let [Node::Alternation(branches)] = sequence.as_mut_slice() {
+        if let Some(last) = branches.last_mut() {
+            last.extend(nodes);
+            return;
+        }
+    }
+    sequence.extend(nodes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::Flags;
+
+    fn raw(expression: &str) -> Node {
+        Node::Raw {
+            expression: expression.to_string(),
+            method: "raw",
+        }
+    }
+
+    #[test]
+    fn render_nested_nodes_works() {
+        let nodes = vec![
+            Node::Assertion(Assertion::StartOfLine),
+            Node::Repetition {
+                node: Box::new(Node::Group {
+                    kind: GroupKind::Named {
+                        name: "year".to_string(),
+                        method: "named_group",
+                    },
+                    nodes: vec![Node::Class {
+                        negated: false,
+                        items: vec![raw("0-9")],
+                        method: "list",
+                    }],
+                }),
+                quantifier: Quantifier::AtLeast(2),
+            },
+            Node::FlagScope {
+                flags: Flags::Insensitive | Flags::SingleLine,
+                nodes: vec![Node::Alternation(vec![vec![raw("am")], vec![raw("pm")]])],
+            },
+        ];
+        assert_eq!("^(?P<year>[0-9]){2,}(?i-m:am|pm)", render(&nodes));
+    }
+
+    #[test]
+    fn is_atom_works() {
+        for atom in [r"\d", "[a-z]", "x", r"\.", r"\p{Greek}", "(abc|d)", "ب"] {
+            assert!(raw(atom).is_atom(), "{}", atom);
+        }
+        for non_atom in ["abc", r"a\d", "a|b", "a+", ""] {
+            assert!(!raw(non_atom).is_atom(), "{}", non_atom);
+        }
+        assert!(Node::Literal(".".to_string()).is_atom());
+        assert!(!Node::Literal("1.5".to_string()).is_atom());
+    }
+
+    #[test]
+    fn captures_works() {
+        let nodes = vec![
+            raw(r"(a)(?:b)(?P<c>c)"),
+            Node::Repetition {
+                node: Box::new(Node::Group {
+                    kind: GroupKind::Capturing { method: "group" },
+                    nodes: vec![raw("(d)")],
+                }),
+                quantifier: Quantifier::Optional,
+            },
+            Node::Class {
+                negated: false,
+                items: vec![raw("(")],
+                method: "list",
+            },
+        ];
+        let mut groups = Vec::new();
+        captures(&nodes, &mut groups);
+        assert_eq!(
+            vec![
+                (None, None),
+                (Some("c".to_string()), None),
+                (None, Some("group")),
+                (None, None)
+            ],
+            groups
+        );
+    }
+
+    #[test]
+    fn append_continues_open_alternation() {
+        let mut nodes = vec![Node::Alternation(vec![vec![raw("abc")], vec![]])];
+        append(&mut nodes, vec![raw("efg")]);
+        assert_eq!("abc|efg", render(&nodes));
+    }
+
+    #[test]
+    fn render_choice_works() {
+        let choice = Node::Choice(vec![
+            vec![raw("jpe?g")],
+            vec![Node::Literal("png".to_string())],
+        ]);
+        assert_eq!("jpe?g|png", render(std::slice::from_ref(&choice)));
+
+        let nodes = vec![Node::Literal(".".to_string()), choice];
+        assert_eq!("\\.(?:jpe?g|png)", render(&nodes));
+    }
+}
@@ -0,0 +1,162 @@
+//! Matches bytes of input which isn't necessarily valid UTF-8.
+//!
+//! The [`get_bytes_regex`](../struct.EasyRegex.html#method.get_bytes_regex) method compiles the prepared pattern into
+//! a [`bytes::Regex`](https://docs.rs/regex/latest/regex/bytes/struct.Regex.html) searching ```&[u8]``` haystacks,
+//! and the [`byte`](../struct.EasyRegex.html#method.byte) and [`byte_range`](../struct.EasyRegex.html#method.byte_range)
+//! methods match raw bytes such as ```\xFF``` with Unicode disabled.
+//!
+//! Bytes above ```0x7F``` could match invalid UTF-8, so patterns having them are only accepted by ```get_bytes_regex```.
+//! Wherever Unicode is disabled, expressions needing it (e.g. ```\p{Greek}``` or the ```PERSIAN_ALPHABET``` constant
+//! inside a list) are reported as ```EasyRegexError::UnicodeRequired```.
+
+use regex::bytes::Regex as BytesRegex;
+
+use crate::{ast::Node, settings::Settings, EasyRegex, EasyRegexError};
+
+impl EasyRegex {
+    /// Adds a single byte, written as **(?-u:\\xNN)**.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::base::*};
+    ///
+    /// let result = EasyRegex::new_section()
+    ///     .byte(0xFF, &DEFAULT)
+    ///     .literal("PK", &DEFAULT)
+    ///     .byte(0x03, &DEFAULT);
+    /// let regex = result.get_bytes_regex().unwrap();
+    /// assert_eq!(r"(?-u:\xFF)PK(?-u:\x03)", regex.as_str());
+    /// assert!(regex.is_match(b"\x00\xFFPK\x03\x04"));
+    /// ```
+    pub fn byte(self, byte: u8, settings: &Settings) -> Self {
+        self.push(byte_nodes("byte", byte, byte, settings))
+    }
+
+    /// Adds a range of bytes, written as **(?-u:[\\xNN-\\xNN])**.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::base::*};
+    ///
+    /// let result = EasyRegex::start_of_line().byte_range(0x80, 0xFF, &ONE_OR_MORE);
+    /// let regex = result.get_bytes_regex().unwrap();
+    /// assert_eq!(r"^(?-u:[\x80-\xFF])+", regex.as_str());
+    /// assert!(regex.is_match(b"\xC3\x28"));
+    /// ```
+    pub fn byte_range(self, from: u8, to: u8, settings: &Settings) -> Self {
+        self.push(byte_nodes("byte_range", from, to, settings))
+    }
+
+    /// Retrieves the prepared regular expression matching bytes rather than strings.
+    pub fn get_bytes_regex(self) -> Result<BytesRegex, EasyRegexError> {
+        self.to_bytes_regex()
+    }
+
+    /// Same as ```get_bytes_regex``` without consuming the instance, i.e. ```compile::<regex::bytes::Regex>()```.
+    pub fn to_bytes_regex(&self) -> Result<BytesRegex, EasyRegexError> {
+        self.compile::<BytesRegex>()
+    }
+}
+
+fn byte_nodes(method: &'static str, from: u8, to: u8, settings: &Settings) -> Vec<Node> {
+    if from > to {
+        return vec![Node::Invalid(EasyRegexError::InvalidRange {
+            method,
+            from: from.into(),
+            to: to.into(),
+        })];
+    }
+    settings.apply(method, Node::Byte { from, to, method })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        collection::PERSIAN_ALPHABET, compile::CompileOptions, settings::base::*, unicode::Script,
+    };
+
+    #[test]
+    fn byte_works() {
+        let result = EasyRegex::new_section()
+            .literal("GIF8", &DEFAULT)
+            .byte_range(0x37, 0x39, &DEFAULT)
+            .byte(0x61, &DEFAULT);
+        assert_eq!(r"GIF8(?-u:[\x37-\x39])(?-u:\x61)", result.as_str());
+        assert!(result.to_regex().unwrap().is_match("GIF89a"));
+        assert!(result.to_bytes_regex().unwrap().is_match(b"GIF87a\xFF"));
+    }
+
+    #[test]
+    fn non_ascii_byte_with_str_regex_fails() {
+        let result = EasyRegex::new_section()
+            .literal("a", &DEFAULT)
+            .byte_range(0x00, 0x80, &DEFAULT);
+        assert_eq!(
+            EasyRegexError::UnsupportedConstruct {
+                method: "byte_range",
+                engine: "regex::Regex"
+            },
+            result.to_regex().unwrap_err()
+        );
+        assert!(result.to_bytes_regex().is_ok());
+    }
+
+    #[test]
+    fn invalid_byte_range_fails() {
+        let result = EasyRegex::new_section().byte_range(0xFF, 0x00, &DEFAULT);
+        assert_eq!(
+            EasyRegexError::InvalidRange {
+                method: "byte_range",
+                from: 255,
+                to: 0
+            },
+            result.get_bytes_regex().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn unicode_only_class_fails() {
+        let expected = EasyRegexError::UnicodeRequired {
+            method: "list",
+            expression: format!("[{}]", PERSIAN_ALPHABET),
+        };
+
+        let result = EasyRegex::new_section()
+            .digit(&DEFAULT)
+            .list(PERSIAN_ALPHABET, &NO_UNICODE);
+        assert_eq!(expected, result.to_bytes_regex().unwrap_err());
+
+        let scoped = EasyRegex::new_section()
+            .list(PERSIAN_ALPHABET, &DEFAULT)
+            .into_no_unicode_non_capturing()
+            .list(PERSIAN_ALPHABET, &DEFAULT);
+        assert_eq!(expected, scoped.to_bytes_regex().unwrap_err());
+
+        let branches = EasyRegex::no_unicode()
+            .raw("a", &DEFAULT)
+            .or()
+            .list(PERSIAN_ALPHABET, &DEFAULT);
+        assert_eq!(expected, branches.to_bytes_regex().unwrap_err());
+
+        let unicode = EasyRegex::new_section()
+            .byte(0xFF, &DEFAULT)
+            .list(PERSIAN_ALPHABET, &DEFAULT);
+        assert!(unicode.to_bytes_regex().is_ok());
+
+        let options = CompileOptions {
+            unicode: Some(false),
+            ..Default::default()
+        };
+        let greek = EasyRegex::new_section().unicode_script(Script::Greek, &DEFAULT);
+        assert_eq!(
+            EasyRegexError::UnicodeRequired {
+                method: "unicode_script",
+                expression: r"\p{Greek}".to_string()
+            },
+            greek.get_regex_with(&options).unwrap_err()
+        );
+    }
+}
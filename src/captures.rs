@@ -0,0 +1,228 @@
+//! Keeps track of capture groups.
+//!
+//! Capture groups are numbered by the regex crate in the order their opening parenthesis appears,
+//! which is hard to follow once groups come from [`group`](../struct.EasyRegex.html#method.group),
+//! [`into_group`](../struct.EasyRegex.html#method.into_group) and constants of the [`collection`](../collection/index.html) module alike.
+//! The [`captures_layout`](../struct.EasyRegex.html#method.captures_layout) method lists them along with their index,
+//! name and the method which created them.
+
+use regex::{Captures, Match, Regex};
+
+use crate::{ast, EasyRegex, EasyRegexError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A capture group of the pattern.
+pub struct CaptureGroup {
+    /// Index of the group, starting from 1 as index 0 is the whole match.
+    pub index: usize,
+    pub name: Option<String>,
+    /// Name of the method which created the group, ```None``` for groups written in expressions inserted as they are.
+    pub method: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Capture groups of a pattern in the order of their indices.
+pub struct CapturesLayout {
+    groups: Vec<CaptureGroup>,
+}
+
+/// Refers to a capture group either by its index or by its name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupId<'a> {
+    Index(usize),
+    Name(&'a str),
+}
+
+impl From<usize> for GroupId<'_> {
+    fn from(index: usize) -> Self {
+        GroupId::Index(index)
+    }
+}
+
+impl<'a> From<&'a str> for GroupId<'a> {
+    fn from(name: &'a str) -> Self {
+        GroupId::Name(name)
+    }
+}
+
+impl CapturesLayout {
+    /// All capture groups, the whole match excluded.
+    pub fn groups(&self) -> &[CaptureGroup] {
+        &self.groups
+    }
+
+    /// Finds the index of a named group.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.groups
+            .iter()
+            .find(|group| group.name.as_deref() == Some(name))
+            .map(|group| group.index)
+    }
+
+    /// Finds a group by its index or name.
+    pub fn find<'a, G: Into<GroupId<'a>>>(&self, group: G) -> Option<&CaptureGroup> {
+        match group.into() {
+            GroupId::Index(index) => self.groups.get(index.checked_sub(1)?),
+            GroupId::Name(name) => self
+                .groups
+                .iter()
+                .find(|group| group.name.as_deref() == Some(name)),
+        }
+    }
+
+    /// Retrieves the match of a group out of the captures of a regex compiled from the same pattern.
+    pub fn get<'h, 'a, G: Into<GroupId<'a>>>(
+        &self,
+        captures: &Captures<'h>,
+        group: G,
+    ) -> Option<Match<'h>> {
+        let index = self.find(group)?.index;
+        captures.get(index)
+    }
+
+    /// Checks that no two groups share a name, reporting the method which created the second group or else the first one.
+    ///
+    /// Duplicates written in expressions inserted as they are have no method and are left to the regex crate.
+    pub(crate) fn validate(&self) -> Result<(), EasyRegexError> {
+        for (position, group) in self.groups.iter().enumerate() {
+            let Some(name) = &group.name else { continue };
+            let duplicate = self.groups[position + 1..]
+                .iter()
+                .find(|other| other.name.as_ref() == Some(name));
+            let Some(other) = duplicate else { continue };
+            if let Some(method) = other.method.or(group.method) {
+                return Err(EasyRegexError::DuplicateGroupName {
+                    method,
+                    name: name.clone(),
+                    first: group.index,
+                    second: other.index,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl EasyRegex {
+    /// Lists the capture groups of the prepared pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, collection::EMAIL, settings::{base::DEFAULT, group::DEFAULT_GROUP}};
+    ///
+    /// let result = EasyRegex::new_section()
+    ///     .named_group("to", EMAIL, &DEFAULT_GROUP)
+    ///     .literal(", ", &DEFAULT)
+    ///     .group(EMAIL, &DEFAULT_GROUP);
+    /// let layout = result.captures_layout();
+    ///
+    /// assert_eq!(6, layout.groups().len());
+    /// assert_eq!(Some(1), layout.index_of("to"));
+    /// assert_eq!(Some("group"), layout.groups()[3].method);
+    /// assert_eq!(None, layout.groups()[4].method); // the username group of EMAIL
+    /// ```
+    pub fn captures_layout(&self) -> CapturesLayout {
+        let mut groups = Vec::new();
+        ast::captures(&self.0, &mut groups);
+        CapturesLayout {
+            groups: groups
+                .into_iter()
+                .enumerate()
+                .map(|(position, (name, method))| CaptureGroup {
+                    index: position + 1,
+                    name,
+                    method,
+                })
+                .collect(),
+        }
+    }
+
+    /// Retrieves the prepared regular expression along with the layout of its capture groups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::{base::*, group::DEFAULT_GROUP}};
+    ///
+    /// let (regex, layout) = EasyRegex::new_section()
+    ///     .digit(&ONE_OR_MORE)
+    ///     .into_named_group("year", &DEFAULT)
+    ///     .literal("-", &DEFAULT)
+    ///     .group(r"\d+", &DEFAULT_GROUP)
+    ///     .get_regex_with_layout()
+    ///     .unwrap();
+    /// let captures = regex.captures("2024-07").unwrap();
+    ///
+    /// assert_eq!("2024", layout.get(&captures, "year").unwrap().as_str());
+    /// assert_eq!("07", layout.get(&captures, 2).unwrap().as_str());
+    /// ```
+    pub fn get_regex_with_layout(self) -> Result<(Regex, CapturesLayout), EasyRegexError> {
+        let regex = self.to_regex()?;
+        Ok((regex, self.captures_layout()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{base::*, group::*};
+
+    #[test]
+    fn captures_layout_works() {
+        let result = EasyRegex::new(r"(\d+)")
+            .group("a", &NON_CAPTURE)
+            .one_of(&["(?P<b>b)", "c"], &DEFAULT)
+            .raw("x", &DEFAULT)
+            .into_insensitive_group()
+            .named_group("d", "(e)", &OPTIONAL_GROUP);
+        let layout = result.captures_layout();
+        let methods = layout
+            .groups()
+            .iter()
+            .map(|group| (group.index, group.name.as_deref(), group.method))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (1, None, Some("into_insensitive_group")),
+                (2, None, None),
+                (3, Some("b"), None),
+                (4, Some("d"), Some("named_group")),
+                (5, None, None),
+            ],
+            methods
+        );
+        assert_eq!(6, result.get_regex().unwrap().captures_len());
+    }
+
+    #[test]
+    fn captures_layout_of_split_raw_group_works() {
+        let result = EasyRegex::new_section()
+            .raw("(", &DEFAULT)
+            .raw("a)", &DEFAULT)
+            .named_group("b", "b", &DEFAULT_GROUP);
+        let layout = result.captures_layout();
+        assert_eq!(2, layout.groups().len());
+        assert_eq!(None, layout.groups()[0].method);
+        assert_eq!(Some(2), layout.index_of("b"));
+        assert_eq!(Some("named_group"), layout.groups()[1].method);
+        assert_eq!(3, result.get_regex().unwrap().captures_len());
+    }
+
+    #[test]
+    fn duplicate_group_name_fails() {
+        let result = EasyRegex::new_section()
+            .named_group("x", "a", &DEFAULT_GROUP)
+            .raw("(b)", &DEFAULT)
+            .into_named_group("x", &DEFAULT);
+        assert_eq!(
+            EasyRegexError::DuplicateGroupName {
+                method: "named_group",
+                name: "x".to_string(),
+                first: 1,
+                second: 2
+            },
+            result.get_regex().unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,456 @@
+//! Builds character classes without escaping them by hand.
+//!
+//! A [`CharClass`] collects single characters, ranges, sets of the [`collection`](../collection/index.html) module
+//! and Unicode properties, escaping what's needed inside brackets (e.g. ```]```, ```^```, ```-``` or ```\```).
+//! It could be given to the [`list_class`](../struct.EasyRegex.html#method.list_class) and
+//! [`not_class`](../struct.EasyRegex.html#method.not_class) methods, or turned into an ```EasyRegex``` section
+//! to be nested by the [`into_list`](../struct.EasyRegex.html#method.into_list) method.
+//!
+//! Classes could be combined by set operations too, rendered as the nested classes of the regex crate,
+//! e.g. ```[\w--\d]``` for word characters except digits.
+
+use crate::{
+    ast::Node,
+    metacharacters::AsciiClass,
+    unicode::{self, Category, Script},
+    EasyRegex, EasyRegexError,
+};
+
+#[derive(Debug, Clone, Default)]
+/// A set of characters rendered as a bracketed list.
+///
+/// # Examples
+///
+/// ```
+/// use easy_regex::{EasyRegex, char_class::CharClass, collection::DIGITS, settings::base::ONE_OR_MORE};
+///
+/// let class = CharClass::new().range('a', 'f').raw(DIGITS).chars("-]^");
+/// let result = EasyRegex::new_section().list_class(class, &ONE_OR_MORE);
+/// assert_eq!(r"[a-f0-9\-\]\^]+", result.get_regex().unwrap().as_str());
+/// ```
+pub struct CharClass {
+    negated: bool,
+    items: Vec<Node>,
+    /// Method of the last set operation, whose result is nested before any item is added.
+    operation: Option<&'static str>,
+}
+
+impl CharClass {
+    /// Creates an empty class.
+    pub fn new() -> Self {
+        CharClass::default()
+    }
+
+    /// Adds a single character, escaped if needed.
+    pub fn char(self, character: char) -> Self {
+        self.push(Node::Literal(character.to_string()))
+    }
+
+    /// Adds every character of the given text, escaped if needed.
+    pub fn chars(self, characters: &str) -> Self {
+        self.push(Node::Literal(characters.to_string()))
+    }
+
+    /// Adds the characters from ```from``` to ```to```, both included.
+    ///
+    /// A range whose start is greater than its end is reported by the ```get_regex``` method.
+    pub fn range(self, from: char, to: char) -> Self {
+        let range = Node::Raw {
+            expression: format!(
+                "{}-{}",
+                regex::escape(&from.to_string()),
+                regex::escape(&to.to_string())
+            ),
+            method: "CharClass::range",
+        };
+        let class = self.push(range);
+        if from > to {
+            class.push(Node::Invalid(EasyRegexError::InvalidClassRange {
+                method: "CharClass::range",
+                from,
+                to,
+            }))
+        } else {
+            class
+        }
+    }
+
+    /// Adds a set inserted as it is, e.g. the ```ALPHA_NUMERIC``` or ```PERSIAN_ALPHABET``` constants of the collection module.
+    pub fn raw(self, set: &str) -> Self {
+        self.push(Node::Raw {
+            expression: set.to_string(),
+            method: "CharClass::raw",
+        })
+    }
+
+    /// Adds the characters having a Unicode property, e.g. ```Greek``` makes ```\p{Greek}```.
+    ///
+    /// Names not known by the regex crate are reported by the ```get_regex``` method.
+    pub fn property(self, name: &str) -> Self {
+        self.property_of("CharClass::property", "p", name)
+    }
+
+    /// Adds the characters not having a Unicode property, e.g. ```Greek``` makes ```\P{Greek}```.
+    pub fn not_property(self, name: &str) -> Self {
+        self.property_of("CharClass::not_property", "P", name)
+    }
+
+    /// Adds the characters of a Unicode script.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, char_class::CharClass, settings::base::ONE_OR_MORE, unicode::Script};
+    ///
+    /// let kana = CharClass::new().script(Script::Hiragana).script(Script::Katakana).char('ー');
+    /// let result = EasyRegex::new_section().list_class(kana, &ONE_OR_MORE);
+    /// assert_eq!(r"[\p{Hiragana}\p{Katakana}ー]+", result.get_regex().unwrap().as_str());
+    /// ```
+    pub fn script(self, script: Script) -> Self {
+        self.push(unicode::property_node(
+            "CharClass::script",
+            "p",
+            script.as_str(),
+        ))
+    }
+
+    /// Adds the characters not in a Unicode script.
+    pub fn not_script(self, script: Script) -> Self {
+        self.push(unicode::property_node(
+            "CharClass::not_script",
+            "P",
+            script.as_str(),
+        ))
+    }
+
+    /// Adds the characters of an ASCII class, e.g. ```[:alpha:]```.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, char_class::CharClass, metacharacters::AsciiClass, settings::base::ONE_OR_MORE};
+    ///
+    /// let token = CharClass::new().ascii(AsciiClass::Alnum).chars("-_.");
+    /// let result = EasyRegex::new_section().list_class(token, &ONE_OR_MORE);
+    /// assert_eq!(r"[[:alnum:]\-_\.]+", result.get_regex().unwrap().as_str());
+    /// ```
+    pub fn ascii(self, class: AsciiClass) -> Self {
+        self.push(class.item("CharClass::ascii", false))
+    }
+
+    /// Adds the characters not in an ASCII class, e.g. ```[:^alpha:]```.
+    pub fn not_ascii(self, class: AsciiClass) -> Self {
+        self.push(class.item("CharClass::not_ascii", true))
+    }
+
+    /// Adds the characters of a Unicode general category.
+    pub fn category(self, category: Category) -> Self {
+        self.push(unicode::property_node(
+            "CharClass::category",
+            "p",
+            category.as_str(),
+        ))
+    }
+
+    /// Adds the characters not in a Unicode general category.
+    pub fn not_category(self, category: Category) -> Self {
+        self.push(unicode::property_node(
+            "CharClass::not_category",
+            "P",
+            category.as_str(),
+        ))
+    }
+
+    /// Matches any character except the ones of the class, i.e. ```[^...]```.
+    ///
+    /// The negation applies to the whole class, including the results of set operations.
+    pub fn negate(mut self) -> Self {
+        self.negated = !self.negated;
+        self
+    }
+
+    /// Adds the characters of another class.
+    pub fn union(self, other: CharClass) -> Self {
+        self.push(other.into_node("CharClass::union"))
+    }
+
+    /// Keeps the characters found in both classes, i.e. ```&&```.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, char_class::CharClass, settings::base::DEFAULT};
+    ///
+    /// let consonants = CharClass::new()
+    ///     .range('a', 'z')
+    ///     .intersection(CharClass::new().chars("aeiou").negate());
+    /// let result = EasyRegex::new_section().list_class(consonants, &DEFAULT);
+    /// assert_eq!("[a-z&&[^aeiou]]", result.get_regex().unwrap().as_str());
+    /// ```
+    pub fn intersection(self, other: CharClass) -> Self {
+        self.operation("CharClass::intersection", "&&", other)
+    }
+
+    /// Removes the characters of another class, i.e. ```--```.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, char_class::CharClass, settings::base::ONE_OR_MORE};
+    ///
+    /// let non_digit_word = CharClass::new().raw(r"\w").difference(CharClass::new().raw(r"\d"));
+    /// let result = EasyRegex::new_section().list_class(non_digit_word, &ONE_OR_MORE);
+    /// assert_eq!(r"[\w--[\d]]+", result.get_regex().unwrap().as_str());
+    /// ```
+    pub fn difference(self, other: CharClass) -> Self {
+        self.operation("CharClass::difference", "--", other)
+    }
+
+    /// Keeps the characters found in only one of the classes, i.e. ```~~```.
+    pub fn symmetric_difference(self, other: CharClass) -> Self {
+        self.operation("CharClass::symmetric_difference", "~~", other)
+    }
+
+    /// Combines two classes, operations chained after each other are applied from left to right.
+    fn operation(mut self, method: &'static str, operator: &str, other: CharClass) -> Self {
+        let has_empty_operand = self.items.is_empty() || other.items.is_empty();
+        self.items.push(Node::Raw {
+            expression: operator.to_string(),
+            method,
+        });
+        self.items.push(other.into_node(method));
+        self.operation = Some(method);
+
+        if has_empty_operand {
+            self.items
+                .push(Node::Invalid(EasyRegexError::EmptyClass { method }));
+        } else if self.matches_nothing(method) {
+            self.items
+                .push(Node::Invalid(EasyRegexError::NoMatchingCharacter {
+                    method,
+                }));
+        }
+        self
+    }
+
+    /// Checks if the class is parsed into an empty set, classes which couldn't be parsed are left to the regex crate.
+    fn matches_nothing(&self, method: &'static str) -> bool {
+        use regex_syntax::hir::{Class, HirKind};
+
+        let pattern = self.clone().into_node(method).to_string();
+        match regex_syntax::parse(&pattern) {
+            Ok(hir) => match hir.kind() {
+                HirKind::Class(Class::Unicode(class)) => class.ranges().is_empty(),
+                HirKind::Class(Class::Bytes(class)) => class.ranges().is_empty(),
+                _ => false,
+            },
+            Err(_) => false,
+        }
+    }
+
+    fn property_of(self, method: &'static str, escape: &str, name: &str) -> Self {
+        let class = self.push(unicode::property_node(method, escape, name));
+        match unicode::validate_property(method, name) {
+            Ok(()) => class,
+            Err(error) => class.push(Node::Invalid(error)),
+        }
+    }
+
+    /// Adds an item to the class, results of set operations are nested first so the item is added to the whole of them.
+    fn push(mut self, item: Node) -> Self {
+        if let Some(method) = self.operation.take() {
+            self.items = vec![Node::Class {
+                negated: false,
+                items: self.items,
+                method,
+            }];
+        }
+        self.items.push(item);
+        self
+    }
+
+    /// Turns the class into a node, given the method which adds it to a pattern.
+    pub(crate) fn into_node(self, method: &'static str) -> Node {
+        Node::Class {
+            negated: self.negated,
+            items: self.items,
+            method,
+        }
+    }
+}
+
+/// A ```CharClass``` given to the ```list_class``` and ```not_class``` methods, either by value or by reference.
+pub trait IntoCharClass {
+    fn into_char_class(self) -> CharClass;
+}
+
+impl IntoCharClass for CharClass {
+    fn into_char_class(self) -> CharClass {
+        self
+    }
+}
+
+impl IntoCharClass for &CharClass {
+    fn into_char_class(self) -> CharClass {
+        self.clone()
+    }
+}
+
+impl From<CharClass> for EasyRegex {
+    fn from(class: CharClass) -> Self {
+        EasyRegex::from_nodes(vec![class.into_node("from")])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{collection::PERSIAN_ALPHABET, settings::base::DEFAULT};
+
+    #[test]
+    fn char_class_escape_works() {
+        let class = CharClass::new().chars(r"\[]^-&~").range('!', '-').char('a');
+        let result = EasyRegex::new_section().list_class(&class, &DEFAULT);
+        assert_eq!(r"[\\\[\]\^\-\&\~!-\-a]", result.as_str());
+
+        let regex = result.get_regex().unwrap();
+        for matching in ["\\", "[", "]", "^", "-", "&", "~", "#", "a"] {
+            assert!(regex.is_match(matching), "{}", matching);
+        }
+        assert!(!regex.is_match("b"));
+    }
+
+    #[test]
+    fn char_class_negate_works() {
+        let class = CharClass::new()
+            .raw(PERSIAN_ALPHABET)
+            .property("Greek")
+            .not_property("L")
+            .negate();
+        let result = EasyRegex::new_section().list_class(class.clone(), &DEFAULT);
+        assert!(result.as_str().starts_with("[^\\u0621-"));
+        assert!(result.as_str().ends_with("\\p{Greek}\\P{L}]"));
+
+        let result = EasyRegex::new_section().not_class(class);
+        assert!(result.as_str().starts_with("[\\u0621-"));
+    }
+
+    #[test]
+    fn char_class_into_list_works() {
+        let result = EasyRegex::new("a-z")
+            .then(CharClass::new().range('0', '9').negate().into())
+            .into_list(&DEFAULT);
+        assert_eq!("[a-z[^0-9]]", result.as_str());
+        assert!(result.get_regex().unwrap().is_match("?"));
+    }
+
+    #[test]
+    fn char_class_set_operations_work() {
+        let letters = CharClass::new().property("Arabic");
+        let persian_only = CharClass::new().chars("پچژگ");
+        let result = EasyRegex::new_section().list_class(
+            letters
+                .clone()
+                .symmetric_difference(persian_only.clone())
+                .difference(CharClass::new().range('\u{0660}', '\u{0669}'))
+                .char('_'),
+            &DEFAULT,
+        );
+        assert_eq!(
+            "[[\\p{Arabic}~~[پچژگ]--[\u{660}-\u{669}]]_]",
+            result.as_str()
+        );
+
+        let regex = result.get_regex().unwrap();
+        assert!(regex.is_match("ع"));
+        assert!(regex.is_match("_"));
+        assert!(!regex.is_match("پ"));
+        assert!(!regex.is_match("٣"));
+
+        let result = EasyRegex::new_section().not_class(letters.union(persian_only));
+        assert_eq!("[^\\p{Arabic}[پچژگ]]", result.as_str());
+    }
+
+    #[test]
+    fn char_class_set_operations_fail() {
+        let result = EasyRegex::new_section().list_class(
+            CharClass::new()
+                .range('a', 'z')
+                .intersection(CharClass::new()),
+            &DEFAULT,
+        );
+        assert_eq!(
+            "CharClass::intersection: one of the classes is empty",
+            result.get_regex().unwrap_err().to_string()
+        );
+
+        let result = EasyRegex::new_section().list_class(
+            CharClass::new()
+                .raw(r"\d")
+                .intersection(CharClass::new().range('a', 'z')),
+            &DEFAULT,
+        );
+        assert_eq!(
+            EasyRegexError::NoMatchingCharacter {
+                method: "CharClass::intersection"
+            },
+            result.get_regex().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn char_class_unicode_works() {
+        let class = CharClass::new()
+            .category(Category::DecimalNumber)
+            .not_category(Category::Letter)
+            .intersection(CharClass::new().not_script(Script::Latin));
+        let result = EasyRegex::new_section().list_class(class, &DEFAULT);
+        assert_eq!(
+            "[\\p{Decimal_Number}\\P{Letter}&&[\\P{Latin}]]",
+            result.as_str()
+        );
+        assert!(result.get_regex().unwrap().is_match("٣"));
+
+        let result = EasyRegex::new_section().list_class(CharClass::new().property("Klingon"), &DEFAULT);
+        assert_eq!(
+            "CharClass::property: 'Klingon' is not a Unicode property known by the regex crate",
+            result.get_regex().unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn char_class_ascii_works() {
+        let class = CharClass::new()
+            .ascii(AsciiClass::Punct)
+            .difference(CharClass::new().chars("\"'"))
+            .not_ascii(AsciiClass::Ascii);
+        let result = EasyRegex::new_section().list_class(class, &DEFAULT);
+        assert_eq!("[[[:punct:]--[\"']][:^ascii:]]", result.as_str());
+
+        let regex = result.get_regex().unwrap();
+        for matching in ["!", "~", "é"] {
+            assert!(regex.is_match(matching), "{}", matching);
+        }
+        assert!(!regex.is_match("'"));
+        assert!(!regex.is_match("a"));
+
+        let result = EasyRegex::new_section()
+            .ascii_class(AsciiClass::Word, &DEFAULT)
+            .non_ascii_class(AsciiClass::Space, &DEFAULT);
+        assert_eq!("[[:word:]][[:^space:]]", result.as_str());
+        assert!(!result.get_regex().unwrap().is_match("éé"));
+    }
+
+    #[test]
+    fn char_class_invalid_range_fails() {
+        let result = EasyRegex::new_section().list_class(CharClass::new().range('z', 'a'), &DEFAULT);
+        assert_eq!(
+            EasyRegexError::InvalidClassRange {
+                method: "CharClass::range",
+                from: 'z',
+                to: 'a'
+            },
+            result.get_regex().unwrap_err()
+        );
+    }
+}
@@ -91,10 +91,12 @@ pub const PERSIAN_PUNCTUATION: &str = r"\u060C\u061B\u061F\u0640\u066A\u066B\u06
 /// Should be used inside the **list** method for its full capability.
 pub const PERSIAN_SPACES: &str = r"\u0020\u2000-\u200F\u2028-\u202F";
 /// Should be used inside the **list** method for its full capability.
+/// Consider the **unicode_script** method for the complete set of characters of the script.
 pub const FRENCH_ALPHABET: &str = r"a-zA-Z\u00C0-\u017F";
 /// Should be used inside the **list** method for its full capability.
 pub const GERMAN_ALPHABET: &str = r"a-zA-Z\u00E4\u00F6\u00FC\u00C4\u00D6\u00DC\u00df";
 /// Should be used inside the **list** method for its full capability.
+/// Consider the **unicode_script** method for the complete set of characters of the script.
 pub const CHINESE_ALPHABET: &str = r"\u4e00-\u9fa5";
 /// Captures hour, minute and optional case-insensitive am/pm in 12-hour clock.
 ///
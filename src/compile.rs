@@ -0,0 +1,143 @@
+//! Options for compiling the prepared pattern.
+//!
+//! The [`get_regex_with`](../struct.EasyRegex.html#method.get_regex_with) method takes a ```CompileOptions``` struct
+//! whose fields map onto the methods of [`RegexBuilder`](https://docs.rs/regex/latest/regex/struct.RegexBuilder.html).
+//! Fields left as ```None``` keep the defaults of the regex crate.
+//!
+//! Only ```get_regex_with``` and ```to_regex_with``` apply the options. The [`compile`](../struct.EasyRegex.html#method.compile) method,
+//! the bytes and fancy-regex getters and [`EasyRegexSet`](../set/struct.EasyRegexSet.html) build with the defaults,
+//! so flags needed there should be set by the ```Settings``` of the chained methods instead, e.g. ```(?i)```.
+
+use regex::{Regex, RegexBuilder};
+
+use crate::{EasyRegex, EasyRegexError};
+
+#[derive(Debug, Clone, Default)]
+/// Builder level options applied to the whole pattern when it is compiled.
+///
+/// They are taken by ```get_regex_with``` and ```to_regex_with``` only, other ways of compiling the pattern ignore them.
+pub struct CompileOptions {
+    pub case_insensitive: Option<bool>,
+    pub multi_line: Option<bool>,
+    pub dot_matches_new_line: Option<bool>,
+    pub ignore_whitespace: Option<bool>,
+    pub swap_greed: Option<bool>,
+    pub unicode: Option<bool>,
+    pub octal: Option<bool>,
+    pub crlf: Option<bool>,
+    pub size_limit: Option<usize>,
+    pub dfa_size_limit: Option<usize>,
+    pub nest_limit: Option<u32>,
+}
+
+impl CompileOptions {
+    /// Sets the given options on a ```RegexBuilder```.
+    pub fn configure<'a>(&self, builder: &'a mut RegexBuilder) -> &'a mut RegexBuilder {
+        if let Some(yes) = self.case_insensitive {
+            builder.case_insensitive(yes);
+        }
+        if let Some(yes) = self.multi_line {
+            builder.multi_line(yes);
+        }
+        if let Some(yes) = self.dot_matches_new_line {
+            builder.dot_matches_new_line(yes);
+        }
+        if let Some(yes) = self.ignore_whitespace {
+            builder.ignore_whitespace(yes);
+        }
+        if let Some(yes) = self.swap_greed {
+            builder.swap_greed(yes);
+        }
+        if let Some(yes) = self.unicode {
+            builder.unicode(yes);
+        }
+        if let Some(yes) = self.octal {
+            builder.octal(yes);
+        }
+        if let Some(yes) = self.crlf {
+            builder.crlf(yes);
+        }
+        if let Some(bytes) = self.size_limit {
+            builder.size_limit(bytes);
+        }
+        if let Some(bytes) = self.dfa_size_limit {
+            builder.dfa_size_limit(bytes);
+        }
+        if let Some(limit) = self.nest_limit {
+            builder.nest_limit(limit);
+        }
+        builder
+    }
+}
+
+impl EasyRegex {
+    /// Retrieves the prepared regular expression compiled with the given options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, compile::CompileOptions, settings::base::DEFAULT};
+    ///
+    /// let options = CompileOptions {
+    ///     case_insensitive: Some(true),
+    ///     size_limit: Some(50 * (1 << 20)),
+    ///     ..Default::default()
+    /// };
+    /// let result = EasyRegex::new_section().literal("soap", &DEFAULT).get_regex_with(&options);
+    /// assert!(result.unwrap().is_match("SoAp"));
+    /// ```
+    pub fn get_regex_with(self, options: &CompileOptions) -> Result<Regex, EasyRegexError> {
+        self.to_regex_with(options)
+    }
+
+    /// Same as ```get_regex_with``` without consuming the instance.
+    pub fn to_regex_with(&self, options: &CompileOptions) -> Result<Regex, EasyRegexError> {
+        self.check::<Regex>(options.unicode.unwrap_or(true))?;
+        let mut builder = RegexBuilder::new(self.as_str());
+        Ok(options.configure(&mut builder).build()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{base::DEFAULT, Settings};
+
+    #[test]
+    fn get_regex_with_options_works() {
+        let options = CompileOptions {
+            multi_line: Some(true),
+            crlf: Some(true),
+            swap_greed: Some(true),
+            ..Default::default()
+        };
+        let result = EasyRegex::start_of_line()
+            .raw("a", &Settings::range(Some(1), Some(3)))
+            .end_of_line()
+            .get_regex_with(&options)
+            .unwrap();
+        assert_eq!(2, result.find_iter("aa\r\naaa\r\n").count());
+
+        let lazy = EasyRegex::new_section()
+            .raw("a", &Settings::range(Some(1), Some(3)))
+            .get_regex_with(&options)
+            .unwrap();
+        assert_eq!("a", lazy.find("aaa").unwrap().as_str());
+    }
+
+    #[test]
+    fn get_regex_with_size_limit_fails() {
+        let options = CompileOptions {
+            size_limit: Some(100),
+            ..Default::default()
+        };
+        let result = EasyRegex::new_section()
+            .word(&Settings::exactly(100))
+            .literal("a", &DEFAULT)
+            .get_regex_with(&options);
+        assert!(matches!(
+            result,
+            Err(EasyRegexError::Regex(regex::Error::CompiledTooBig(100)))
+        ));
+    }
+}
@@ -0,0 +1,213 @@
+//! Compiles the prepared pattern into different regex types.
+//!
+//! The [`compile`](../struct.EasyRegex.html#method.compile) method takes any type implementing the ```RegexEngine``` trait,
+//! which is implemented for [`Regex`](https://docs.rs/regex/latest/regex/struct.Regex.html),
+//! [`bytes::Regex`](https://docs.rs/regex/latest/regex/bytes/struct.Regex.html) and
+//! [`RegexSet`](https://docs.rs/regex/latest/regex/struct.RegexSet.html) of the regex crate,
+//! as well as the regex types of the fancy-regex and regex-automata crates behind the features of the same names.
+//!
+//! Each engine declares the constructs it supports beyond plain regular expressions, so a pattern
+//! having e.g. a backreference is rejected before compiling with the name of the method which added it.
+
+use crate::{ast, EasyRegex, EasyRegexError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Constructs not every engine supports.
+pub enum Construct {
+    /// Added by the ```backreference``` method.
+    Backreference,
+    /// Added by the methods of the [`lookaround`](../lookaround/index.html) module.
+    Lookaround,
+    /// A byte above ```0x7F``` added by ```byte``` or ```byte_range```, which could match invalid UTF-8.
+    NonAsciiByte,
+}
+
+/// A regex type the prepared pattern could be compiled into.
+pub trait RegexEngine: Sized {
+    /// Name of the engine used in error messages, e.g. ```regex::Regex```.
+    const NAME: &'static str;
+
+    /// Checks if the engine supports the given construct, none of them by default.
+    fn supports(construct: Construct) -> bool {
+        let _ = construct;
+        false
+    }
+
+    /// Compiles a pattern already checked against the supported constructs.
+    fn build(pattern: &str) -> Result<Self, EasyRegexError>;
+}
+
+impl RegexEngine for regex::Regex {
+    const NAME: &'static str = "regex::Regex";
+
+    fn build(pattern: &str) -> Result<Self, EasyRegexError> {
+        Ok(regex::Regex::new(pattern)?)
+    }
+}
+
+impl RegexEngine for regex::bytes::Regex {
+    const NAME: &'static str = "regex::bytes::Regex";
+
+    fn supports(construct: Construct) -> bool {
+        construct == Construct::NonAsciiByte
+    }
+
+    fn build(pattern: &str) -> Result<Self, EasyRegexError> {
+        Ok(regex::bytes::Regex::new(pattern)?)
+    }
+}
+
+impl RegexEngine for regex::RegexSet {
+    const NAME: &'static str = "regex::RegexSet";
+
+    fn build(pattern: &str) -> Result<Self, EasyRegexError> {
+        Ok(regex::RegexSet::new([pattern])?)
+    }
+}
+
+#[cfg(feature = "fancy-regex")]
+impl RegexEngine for fancy_regex::Regex {
+    const NAME: &'static str = "fancy_regex::Regex";
+
+    fn supports(construct: Construct) -> bool {
+        match construct {
+            Construct::Backreference | Construct::Lookaround => true,
+            Construct::NonAsciiByte => false,
+        }
+    }
+
+    fn build(pattern: &str) -> Result<Self, EasyRegexError> {
+        fancy_regex::Regex::new(pattern)
+            .map_err(|error| EasyRegexError::FancyRegex(error.to_string()))
+    }
+}
+
+#[cfg(feature = "regex-automata")]
+impl RegexEngine for regex_automata::meta::Regex {
+    const NAME: &'static str = "regex_automata::meta::Regex";
+
+    fn build(pattern: &str) -> Result<Self, EasyRegexError> {
+        regex_automata::meta::Regex::new(pattern)
+            .map_err(|error| EasyRegexError::RegexAutomata(error.to_string()))
+    }
+}
+
+impl EasyRegex {
+    /// Retrieves the prepared regular expression compiled into the given engine.
+    ///
+    /// The engine is built with its defaults, ```CompileOptions``` are applied by ```get_regex_with``` only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::base::*};
+    ///
+    /// let result = EasyRegex::new_section().literal("ab", &DEFAULT).digit(&ONE_OR_MORE);
+    /// let regex = result.compile::<regex::bytes::Regex>().unwrap();
+    /// assert!(regex.is_match(b"\xFFab12"));
+    ///
+    /// let set = result.compile::<regex::RegexSet>().unwrap();
+    /// assert!(set.matches("ab1").matched(0));
+    /// ```
+    pub fn compile<E: RegexEngine>(&self) -> Result<E, EasyRegexError> {
+        self.check::<E>(true)?;
+        E::build(self.as_str())
+    }
+
+    /// Reports misuse of the chained methods, duplicate group names, constructs the engine doesn't support
+    /// and expressions needing Unicode where it is disabled, given whether the pattern starts with Unicode enabled.
+    pub(crate) fn check<E: RegexEngine>(&self, unicode: bool) -> Result<(), EasyRegexError> {
+        if let Some(error) = ast::find_error(&self.0) {
+            return Err(error.clone());
+        }
+        if let Some((method, expression)) = ast::find_unicode_only(&self.0, unicode) {
+            return Err(EasyRegexError::UnicodeRequired { method, expression });
+        }
+        let layout = self.captures_layout();
+        layout.validate()?;
+
+        if let Some(method) = ast::find_unsupported(&self.0, E::supports) {
+            return Err(EasyRegexError::UnsupportedConstruct {
+                method,
+                engine: E::NAME,
+            });
+        }
+        #[cfg(feature = "fancy-regex")]
+        {
+            let mut references = Vec::new();
+            ast::backreferences(&self.0, &mut references);
+            for group in references {
+                let id = match group.parse::<usize>() {
+                    Ok(index) => crate::captures::GroupId::Index(index),
+                    Err(_) => crate::captures::GroupId::Name(group),
+                };
+                if layout.find(id).is_none() {
+                    return Err(EasyRegexError::UnknownGroup {
+                        method: "backreference",
+                        group: group.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::base::*;
+
+    #[test]
+    fn compile_works() {
+        let result = EasyRegex::start_of_line()
+            .word(&ONE_OR_MORE)
+            .literal("@", &DEFAULT)
+            .end_of_line();
+        assert_eq!(
+            result.as_str(),
+            result.compile::<regex::Regex>().unwrap().as_str()
+        );
+        assert!(result
+            .compile::<regex::bytes::Regex>()
+            .unwrap()
+            .is_match(b"abc@"));
+        assert_eq!(1, result.compile::<regex::RegexSet>().unwrap().len());
+    }
+
+    #[test]
+    fn compile_reports_misuse() {
+        let result = EasyRegex::new_section()
+            .digit(&crate::settings::Settings::range(Some(3), Some(1)))
+            .compile::<regex::bytes::Regex>();
+        assert!(matches!(
+            result,
+            Err(EasyRegexError::InvalidRange {
+                method: "digit",
+                ..
+            })
+        ));
+    }
+
+    #[cfg(feature = "fancy-regex")]
+    #[test]
+    fn unsupported_construct_fails() {
+        let result = EasyRegex::new(r"(\d)").backreference(1, &DEFAULT);
+        assert_eq!(
+            EasyRegexError::UnsupportedConstruct {
+                method: "backreference",
+                engine: "regex::bytes::Regex"
+            },
+            result.compile::<regex::bytes::Regex>().unwrap_err()
+        );
+        assert!(result.compile::<fancy_regex::Regex>().is_ok());
+    }
+
+    #[cfg(feature = "regex-automata")]
+    #[test]
+    fn regex_automata_works() {
+        let result = EasyRegex::new_section().digit(&ONE_OR_MORE);
+        let regex = result.compile::<regex_automata::meta::Regex>().unwrap();
+        assert_eq!(Some(2..5), regex.find("ab123").map(|found| found.range()));
+    }
+}
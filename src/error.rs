@@ -0,0 +1,197 @@
+//! Errors that may happen while building or compiling a regular expression.
+//!
+//! Chained methods don't return a ```Result``` themselves, so misuse such as an invalid range is recorded in the pattern
+//! and reported by [`get_regex`](../struct.EasyRegex.html#method.get_regex) along with the name of the offending method.
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::settings::Quantifier;
+
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+/// Crate-level error covering builder validation, helper lookups and regex compilation.
+///
+/// Variants are added by features such as ```fancy-regex```, so matching on it needs a wildcard arm.
+pub enum EasyRegexError {
+    /// A range whose start is greater than its end, e.g. ```{5,2}```.
+    InvalidRange {
+        method: &'static str,
+        from: u32,
+        to: u32,
+    },
+    /// A range having neither a start nor an end.
+    EmptyRange { method: &'static str },
+    /// More than one quantifier is set for the same expression, e.g. ```*+```.
+    ConflictingQuantifiers {
+        method: &'static str,
+        quantifiers: Vec<Quantifier>,
+    },
+    /// A range of a character class whose start is greater than its end, e.g. ```z-a```.
+    InvalidClassRange {
+        method: &'static str,
+        from: char,
+        to: char,
+    },
+    /// A set operation of character classes is given an empty class.
+    EmptyClass { method: &'static str },
+    /// A set operation of character classes results in a class matching no character.
+    NoMatchingCharacter { method: &'static str },
+    /// A Unicode property name not known by the regex crate.
+    UnknownProperty { method: &'static str, name: String },
+    /// Two capture groups share the same name.
+    DuplicateGroupName {
+        method: &'static str,
+        name: String,
+        first: usize,
+        second: usize,
+    },
+    /// Two patterns of an ```EasyRegexSet``` share the same name.
+    DuplicatePatternName { name: String },
+    /// An alternation method is given no branches.
+    EmptyAlternation { method: &'static str },
+    /// An alternation is turned into a list, where ```|``` would be matched literally.
+    AlternationInList { method: &'static str },
+    /// A construct such as a backreference or a lookaround, which the regex crate doesn't support.
+    RequiresFancyRegex { method: &'static str },
+    /// A construct such as a backreference, which the engine the pattern is compiled into doesn't support.
+    UnsupportedConstruct {
+        method: &'static str,
+        engine: &'static str,
+    },
+    /// An expression such as ```\p{Greek}``` or a class of non-ASCII characters where Unicode is disabled.
+    UnicodeRequired {
+        method: &'static str,
+        expression: String,
+    },
+    /// A backreference to a group the pattern doesn't have.
+    UnknownGroup { method: &'static str, group: String },
+    /// No quantifier is set where one is expected.
+    MissingQuantifier,
+    /// A letter not found by a helper method.
+    InvalidLetter(String),
+    /// Two letters given to a helper method in the wrong order.
+    LettersOrder { from: String, to: String },
+    /// The prepared pattern couldn't be compiled by the regex crate.
+    Regex(regex::Error),
+    /// The prepared pattern couldn't be compiled by the fancy-regex crate, holding the message of its error.
+    #[cfg(feature = "fancy-regex")]
+    FancyRegex(String),
+    /// The prepared pattern couldn't be compiled by the regex-automata crate, holding the message of its error.
+    #[cfg(feature = "regex-automata")]
+    RegexAutomata(String),
+}
+
+impl Display for EasyRegexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            EasyRegexError::InvalidRange { method, from, to } => write!(
+                f,
+                "{}: range start {} is greater than its end {}",
+                method, from, to
+            ),
+            EasyRegexError::EmptyRange { method } => {
+                write!(f, "{}: range has neither a start nor an end", method)
+            }
+            EasyRegexError::ConflictingQuantifiers {
+                method,
+                quantifiers,
+            } => {
+                let stringified = quantifiers
+                    .iter()
+                    .map(|quantifier| quantifier.to_string())
+                    .collect::<Vec<_>>();
+                write!(
+                    f,
+                    "{}: conflicting quantifiers '{}' are set",
+                    method,
+                    stringified.join("', '")
+                )
+            }
+            EasyRegexError::InvalidClassRange { method, from, to } => write!(
+                f,
+                "{}: class range start '{}' is greater than its end '{}'",
+                method, from, to
+            ),
+            EasyRegexError::EmptyClass { method } => {
+                write!(f, "{}: one of the classes is empty", method)
+            }
+            EasyRegexError::NoMatchingCharacter { method } => {
+                write!(f, "{}: the resulting class matches no character", method)
+            }
+            EasyRegexError::UnknownProperty { method, name } => write!(
+                f,
+                "{}: '{}' is not a Unicode property known by the regex crate",
+                method, name
+            ),
+            EasyRegexError::DuplicateGroupName {
+                method,
+                name,
+                first,
+                second,
+            } => write!(
+                f,
+                "{}: group name '{}' is used by both groups {} and {}",
+                method, name, first, second
+            ),
+            EasyRegexError::DuplicatePatternName { name } => {
+                write!(f, "pattern name '{}' is used more than once in the set", name)
+            }
+            EasyRegexError::EmptyAlternation { method } => {
+                write!(f, "{}: no branches are given", method)
+            }
+            EasyRegexError::AlternationInList { method } => write!(
+                f,
+                "{}: an alternation cannot be turned into a list, its '|' would be matched literally",
+                method
+            ),
+            EasyRegexError::RequiresFancyRegex { method } => write!(
+                f,
+                "{}: not supported by the regex crate, enable the fancy-regex feature and use get_fancy_regex",
+                method
+            ),
+            EasyRegexError::UnsupportedConstruct { method, engine } => {
+                write!(f, "{}: not supported by {}", method, engine)
+            }
+            EasyRegexError::UnicodeRequired { method, expression } => write!(
+                f,
+                "{}: '{}' needs Unicode, which is disabled by the (?-u) flag",
+                method, expression
+            ),
+            EasyRegexError::UnknownGroup { method, group } => {
+                write!(f, "{}: the pattern has no group '{}'", method, group)
+            }
+            EasyRegexError::MissingQuantifier => f.write_str("no quantifier is set"),
+            EasyRegexError::InvalidLetter(letter) => {
+                write!(f, "letter '{}' is not valid", letter)
+            }
+            EasyRegexError::LettersOrder { from, to } => write!(
+                f,
+                "letter '{}' is after letter '{}', consider swapping them",
+                from, to
+            ),
+            EasyRegexError::Regex(error) => Display::fmt(error, f),
+            #[cfg(feature = "fancy-regex")]
+            EasyRegexError::FancyRegex(message) => f.write_str(message),
+            #[cfg(feature = "regex-automata")]
+            EasyRegexError::RegexAutomata(message) => f.write_str(message),
+        }
+    }
+}
+
+impl Error for EasyRegexError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EasyRegexError::Regex(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<regex::Error> for EasyRegexError {
+    fn from(error: regex::Error) -> Self {
+        EasyRegexError::Regex(error)
+    }
+}
@@ -0,0 +1,75 @@
+//! Compiles patterns with the [fancy-regex](https://crates.io/crates/fancy-regex) crate.
+//!
+//! Available with the ```fancy-regex``` feature, it allows constructs the regex crate rejects,
+//! such as the backreferences added by [`backreference`](../struct.EasyRegex.html#method.backreference)
+//! and the lookarounds of the [`lookaround`](../lookaround/index.html) module.
+//! Patterns without them are still better compiled by [`get_regex`](../struct.EasyRegex.html#method.get_regex),
+//! since fancy-regex falls back to backtracking for the parts it handles itself.
+
+use fancy_regex::Regex as FancyRegex;
+
+use crate::{EasyRegex, EasyRegexError};
+
+impl EasyRegex {
+    /// Retrieves the prepared regular expression compiled by the fancy-regex crate.
+    ///
+    /// Backreferences to groups the pattern doesn't have are reported as ```EasyRegexError::UnknownGroup```.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::{base::*, group::DEFAULT_GROUP}};
+    ///
+    /// let result = EasyRegex::new_section()
+    ///     .word_boundary()
+    ///     .group(r"\w+", &DEFAULT_GROUP)
+    ///     .whitespace(&ONE_OR_MORE)
+    ///     .backreference(1, &DEFAULT)
+    ///     .word_boundary();
+    /// let regex = result.get_fancy_regex().unwrap();
+    /// let repeated = regex.captures("it is is fine").unwrap().unwrap();
+    /// assert_eq!("is", &repeated[1]);
+    /// ```
+    pub fn get_fancy_regex(self) -> Result<FancyRegex, EasyRegexError> {
+        self.to_fancy_regex()
+    }
+
+    /// Same as ```get_fancy_regex``` without consuming the instance, i.e. ```compile::<fancy_regex::Regex>()```.
+    pub fn to_fancy_regex(&self) -> Result<FancyRegex, EasyRegexError> {
+        self.compile::<FancyRegex>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{base::*, group::*};
+
+    #[test]
+    fn backreference_works() {
+        let result = EasyRegex::start_of_line()
+            .named_group("tag", r"\w+", &DEFAULT_GROUP)
+            .literal(":", &DEFAULT)
+            .backreference("tag", &ONE_OR_MORE)
+            .end_of_line();
+        assert_eq!(r"^(?P<tag>\w+):\k<tag>+$", result.as_str());
+        let regex = result.to_fancy_regex().unwrap();
+        assert!(regex.is_match("ab:abab").unwrap());
+        assert!(!regex.is_match("ab:ba").unwrap());
+    }
+
+    #[test]
+    fn backreference_to_unknown_group_fails() {
+        let result = EasyRegex::new(r"(\d)")
+            .group("a", &NON_CAPTURE)
+            .backreference(2, &DEFAULT)
+            .get_fancy_regex();
+        assert_eq!(
+            EasyRegexError::UnknownGroup {
+                method: "backreference",
+                group: "2".to_string()
+            },
+            result.unwrap_err()
+        );
+    }
+}
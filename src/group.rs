@@ -7,7 +7,9 @@
 //! They mostly take no arguments for settings and are useful to be added to the latter part of a method chain.
 
 use crate::{
-    settings::{GroupSettings, Settings, base::DEFAULT},
+    ast::{GroupKind, Node},
+    captures::GroupId,
+    settings::{FlagSet, Flags, GroupSettings, Settings},
     EasyRegex,
 };
 
@@ -27,34 +29,34 @@ impl EasyRegex {
     /// assert_eq!("(expression)?", result.get_regex().unwrap().as_str());
     /// ```
     pub fn group(self, expression: &str, group_sttings: &GroupSettings) -> Self {
-        let mut final_result = EasyRegex::new_section();
-
-        // to make the regex itself clearer, this extra if condition is added.
-        if group_sttings.other.flags.is_some() && group_sttings.is_non_capture {
-            final_result.0 = format!(
-                "({}:{})",
-                group_sttings.other.flags.unwrap().as_str(),
-                expression
-            );
-        } else {
-            final_result = final_result
-                .literal(
-                    expression,
-                    &Settings {
-                        flags: group_sttings.other.flags,
-                        ..Default::default()
-                    },
-                )
-                .into_group(&Settings {
-                    flags: None,
-                    ..group_sttings.other
-                });
-            if group_sttings.is_non_capture {
-                final_result.0.insert_str(1, "?:");
-            }
-        }
+        let kind = group_kind("group", group_sttings);
+        let nodes = group_nodes(
+            "group",
+            kind,
+            vec![Node::Raw {
+                expression: expression.to_string(),
+                method: "group",
+            }],
+            group_sttings,
+        );
+        self.push(nodes)
+    }
 
-        self.literal(&final_result.0, &DEFAULT)
+    /// Same as the ```group``` method taking another ```EasyRegex``` section instead of an expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::{base::ONE_OR_MORE, group::OPTIONAL_GROUP}};
+    ///
+    /// let port = EasyRegex::new_section().literal(":", &ONE_OR_MORE).digit(&ONE_OR_MORE);
+    /// let result = EasyRegex::new_section().group_of(port, &OPTIONAL_GROUP);
+    /// assert_eq!(r"(:+\d+)?", result.get_regex().unwrap().as_str());
+    /// ```
+    pub fn group_of(self, section: EasyRegex, group_settings: &GroupSettings) -> Self {
+        let kind = group_kind("group_of", group_settings);
+        let nodes = group_nodes("group_of", kind, section.0, group_settings);
+        self.push(nodes)
     }
 
     /// Same as the ```group``` method with the option to add a custom name to the group.
@@ -68,8 +70,20 @@ impl EasyRegex {
     /// assert_eq!("(?P<my_group>expression)?", result.get_regex().unwrap().as_str());
     /// ```
     pub fn named_group(self, name: &str, expression: &str, group_settings: &GroupSettings) -> Self {
-        let final_result = format!("?P<{}>{}", name, expression);
-        self.group(&final_result, &group_settings)
+        let kind = GroupKind::Named {
+            name: name.to_string(),
+            method: "named_group",
+        };
+        let nodes = group_nodes(
+            "named_group",
+            kind,
+            vec![Node::Raw {
+                expression: expression.to_string(),
+                method: "named_group",
+            }],
+            group_settings,
+        );
+        self.push(nodes)
     }
 
     /// Turns the previous expressions into a **capturing** group. It uses ```Settings``` struct for the settings parameter.
@@ -83,132 +97,331 @@ impl EasyRegex {
     /// assert_eq!(r"(\d{3})?", result.get_regex().unwrap().as_str());
     /// ```
     pub fn into_group(self, settings: &Settings) -> Self {
-        let raw_result = format!("({})", self.0);
-        let final_result = EasyRegex(String::new()).literal(&raw_result, &settings);
-        final_result
+        let group = Node::Group {
+            kind: GroupKind::Capturing {
+                method: "into_group",
+            },
+            nodes: self.0,
+        };
+        EasyRegex::from_nodes(settings.apply("into_group", group))
     }
 
     /// A variation of ```into_group``` having *name* option **(?P\<name\>RegExp)**.
     pub fn into_named_group(self, name: &str, settings: &Settings) -> Self {
-        let raw_result = format!("(?P<{}>{})", name, self.0);
-        let final_result = EasyRegex(String::new()).literal(&raw_result, &settings);
-        final_result
+        let group = Node::Group {
+            kind: GroupKind::Named {
+                name: name.to_string(),
+                method: "into_named_group",
+            },
+            nodes: self.0,
+        };
+        EasyRegex::from_nodes(settings.apply("into_named_group", group))
     }
 
     /// A variation of ```into_group``` having *non-capturing* option **(?:RegExp)**.
     pub fn into_non_capturing(self) -> Self {
-        let result = format!("(?:{})", self.0);
-        EasyRegex(result)
+        EasyRegex::from_nodes(vec![Node::Group {
+            kind: GroupKind::NonCapturing,
+            nodes: self.0,
+        }])
     }
-    ////////////////////////////////////////////////////////////////
+
+    //////////////////////////////////////////////////////////////////
     /// A variation of ```into_group``` having *Insensitive* flag **(?i)**.
     pub fn into_insensitive_group(self) -> Self {
-        let result = format!("((?i){})", self.0);
-        EasyRegex(result)
+        self.into_flagged_group("into_insensitive_group", Flags::Insensitive)
     }
 
     /// A variation of ```into_group``` having *Multiline* flag **(?m)**.
     pub fn into_multline_group(self) -> Self {
-        let result = format!("((?m){})", self.0);
-        EasyRegex(result)
+        self.into_flagged_group("into_multline_group", Flags::Multiline)
     }
 
     /// A variation of ```into_group``` having *Dot All* flag **(?s)**.
     pub fn into_dot_match_newline_group(self) -> Self {
-        let result = format!("((?s){})", self.0);
-        EasyRegex(result)
+        self.into_flagged_group("into_dot_match_newline_group", Flags::DotMatchNewLine)
     }
 
     /// A variation of ```into_group``` ignoring *whitespaces* **(?x)**.
     pub fn into_ignore_whitespace_group(self) -> Self {
-        let result = format!("((?x){})", self.0);
-        EasyRegex(result)
+        self.into_flagged_group("into_ignore_whitespace_group", Flags::IgnoreWhitespace)
     }
-    ////////////////////////////////////////////////////////////////
+
+    //////////////////////////////////////////////////////////////////
     /// A variation of ```into_non_capturing``` having *Insensitive* flag **(?i)**.
     pub fn into_insensitive_non_capturing(self) -> Self {
-        let result = format!("(?i:{})", self.0);
-        EasyRegex(result)
+        self.into_flag_scope(Flags::Insensitive)
     }
 
     /// A variation of ```into_non_capturing``` having *Multiline* flag **(?m)**.
     pub fn into_multiline_non_capturing(self) -> Self {
-        let result = format!("(?m:{})", self.0);
-        EasyRegex(result)
+        self.into_flag_scope(Flags::Multiline)
     }
 
     /// A variation of ```into_non_capturing``` having *Dot All* flag **(?s)**.
     pub fn into_dot_match_newline_non_capturing(self) -> Self {
-        let result = format!("(?s:{})", self.0);
-        EasyRegex(result)
+        self.into_flag_scope(Flags::DotMatchNewLine)
     }
 
     /// A variation of ```into_non_capturing``` ignoring *whitespaces* **(?x)**.
     pub fn into_ignore_whitespace_non_capturing(self) -> Self {
-        let result = format!("(?x:{})", self.0);
-        EasyRegex(result)
+        self.into_flag_scope(Flags::IgnoreWhitespace)
     }
-    ////////////////////////////////////////////////////////////////
+
+    //////////////////////////////////////////////////////////////////
     /// A variation of ```into_group``` having *Insensitive* flag cleared **(?-i)**.
     pub fn into_sensitive_group(self) -> Self {
-        let result = format!("((?-i){})", self.0);
-        EasyRegex(result)
+        self.into_flagged_group("into_sensitive_group", Flags::Sensitive)
     }
 
     /// A variation of ```into_group``` having *Multiline* flag cleared **(?-m)**.
     pub fn into_single_line_group(self) -> Self {
-        let result = format!("((?-m){})", self.0);
-        EasyRegex(result)
+        self.into_flagged_group("into_single_line_group", Flags::SingleLine)
     }
 
     /// A variation of ```into_group``` having *Dot All* flag cleared **(?-s)**.
     pub fn into_dot_dismatch_newline_group(self) -> Self {
-        let result = format!("((?-s){})", self.0);
-        EasyRegex(result)
+        self.into_flagged_group("into_dot_dismatch_newline_group", Flags::DotDisMatchNewLine)
     }
 
     /// A variation of ```into_group``` taking *whitespaces* into account **(?-x)**.
     pub fn into_include_whitespace_group(self) -> Self {
-        let result = format!("((?-x){})", self.0);
-        EasyRegex(result)
+        self.into_flagged_group("into_include_whitespace_group", Flags::IncludeWhitespace)
     }
-    ////////////////////////////////////////////////////////////////
+
+    //////////////////////////////////////////////////////////////////
     /// A variation of ```into_non_capturing``` having *Insensitive* flag cleared **(?-i)**.
     pub fn into_sensitive_non_capturing(self) -> Self {
-        let result = format!("(?-i:{})", self.0);
-        EasyRegex(result)
+        self.into_flag_scope(Flags::Sensitive)
     }
 
     /// A variation of ```into_non_capturing``` having *Multiline* flag cleared **(?-m)**.
     pub fn into_single_line_non_capturing(self) -> Self {
-        let result = format!("(?-m:{})", self.0);
-        EasyRegex(result)
+        self.into_flag_scope(Flags::SingleLine)
     }
 
     /// A variation of ```into_non_capturing``` having *Dot All* flag cleared **(?-s)**.
     pub fn into_dot_dismatch_newline_non_capturing(self) -> Self {
-        let result = format!("(?-s:{})", self.0);
-        EasyRegex(result)
+        self.into_flag_scope(Flags::DotDisMatchNewLine)
     }
 
     /// A variation of ```into_non_capturing``` taking *whitespaces* into account **(?-x)**.
     pub fn into_include_whitespace_non_capturing(self) -> Self {
-        let result = format!("(?-x:{})", self.0);
-        EasyRegex(result)
+        self.into_flag_scope(Flags::IncludeWhitespace)
+    }
+
+    //////////////////////////////////////////////////////////////////
+    /// A variation of ```into_group``` having *Swap Greed* flag **(?U)**.
+    pub fn into_swap_greed_group(self) -> Self {
+        self.into_flagged_group("into_swap_greed_group", Flags::SwapGreed)
+    }
+
+    /// A variation of ```into_group``` having *CRLF* flag **(?R)**.
+    pub fn into_crlf_group(self) -> Self {
+        self.into_flagged_group("into_crlf_group", Flags::Crlf)
+    }
+
+    /// A variation of ```into_group``` having *Unicode* flag **(?u)**.
+    pub fn into_unicode_group(self) -> Self {
+        self.into_flagged_group("into_unicode_group", Flags::Unicode)
+    }
+
+    /// A variation of ```into_non_capturing``` having *Swap Greed* flag **(?U)**.
+    pub fn into_swap_greed_non_capturing(self) -> Self {
+        self.into_flag_scope(Flags::SwapGreed)
+    }
+
+    /// A variation of ```into_non_capturing``` having *CRLF* flag **(?R)**.
+    pub fn into_crlf_non_capturing(self) -> Self {
+        self.into_flag_scope(Flags::Crlf)
+    }
+
+    /// A variation of ```into_non_capturing``` having *Unicode* flag **(?u)**.
+    pub fn into_unicode_non_capturing(self) -> Self {
+        self.into_flag_scope(Flags::Unicode)
+    }
+
+    //////////////////////////////////////////////////////////////////
+    /// A variation of ```into_group``` having *Swap Greed* flag cleared **(?-U)**.
+    pub fn into_no_swap_greed_group(self) -> Self {
+        self.into_flagged_group("into_no_swap_greed_group", Flags::NoSwapGreed)
+    }
+
+    /// A variation of ```into_group``` having *CRLF* flag cleared **(?-R)**.
+    pub fn into_no_crlf_group(self) -> Self {
+        self.into_flagged_group("into_no_crlf_group", Flags::NoCrlf)
+    }
+
+    /// A variation of ```into_group``` having *Unicode* flag cleared **(?-u)**.
+    pub fn into_no_unicode_group(self) -> Self {
+        self.into_flagged_group("into_no_unicode_group", Flags::NoUnicode)
+    }
+
+    /// A variation of ```into_non_capturing``` having *Swap Greed* flag cleared **(?-U)**.
+    pub fn into_no_swap_greed_non_capturing(self) -> Self {
+        self.into_flag_scope(Flags::NoSwapGreed)
+    }
+
+    /// A variation of ```into_non_capturing``` having *CRLF* flag cleared **(?-R)**.
+    pub fn into_no_crlf_non_capturing(self) -> Self {
+        self.into_flag_scope(Flags::NoCrlf)
+    }
+
+    /// A variation of ```into_non_capturing``` having *Unicode* flag cleared **(?-u)**.
+    pub fn into_no_unicode_non_capturing(self) -> Self {
+        self.into_flag_scope(Flags::NoUnicode)
+    }
+
+    /// Wraps the previous expressions into a capturing group starting with the given flag, e.g. **((?i)RegExp)**.
+    fn into_flagged_group(self, method: &'static str, flag: Flags) -> Self {
+        let mut nodes = vec![Node::Flags(flag.into())];
+        nodes.extend(self.0);
+        EasyRegex::from_nodes(vec![Node::Group {
+            kind: GroupKind::Capturing { method },
+            nodes,
+        }])
+    }
+
+    /// Wraps the previous expressions into a non-capturing group scoped to the given flag, e.g. **(?i:RegExp)**.
+    fn into_flag_scope(self, flag: Flags) -> Self {
+        EasyRegex::from_nodes(vec![Node::FlagScope {
+            flags: flag.into(),
+            nodes: self.0,
+        }])
+    }
+
+    /// Scopes flags to a sub-chain, making a non-capturing group such as **(?is-m:RegExp)**.
+    ///
+    /// The closure takes a new section, and flags are applied to whatever it chains,
+    /// leaving the rest of the pattern unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::{Flags, base::DEFAULT}};
+    ///
+    /// let result = EasyRegex::new_section()
+    ///     .literal("id:", &DEFAULT)
+    ///     .with_flags(Flags::Insensitive | Flags::DotMatchNewLine, |section| {
+    ///         section.literal("abc", &DEFAULT).raw(".", &DEFAULT)
+    ///     })
+    ///     .literal("x", &DEFAULT);
+    /// let regex = result.get_regex().unwrap();
+    /// assert_eq!("id:(?is:abc.)x", regex.as_str());
+    /// assert!(regex.is_match("id:ABC\nx"));
+    /// assert!(!regex.is_match("ID:abc\nx"));
+    /// ```
+    pub fn with_flags<S, F>(self, flags: S, section: F) -> Self
+    where
+        S: Into<FlagSet>,
+        F: FnOnce(EasyRegex) -> EasyRegex,
+    {
+        let scoped = section(EasyRegex::new_section());
+        self.push(vec![Node::FlagScope {
+            flags: flags.into(),
+            nodes: scoped.0,
+        }])
+    }
+
+    /// Matches the same text as a previous group, given by its index or name, written as **\\k\<group\>**.
+    ///
+    /// The regex crate doesn't support backreferences, so the pattern has to be compiled
+    /// by [`get_fancy_regex`](struct.EasyRegex.html#method.get_fancy_regex) which needs the ```fancy-regex``` feature.
+    /// Without the feature, the method adds nothing and ```get_regex``` reports ```EasyRegexError::RequiresFancyRegex```.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "fancy-regex")] {
+    /// use easy_regex::{EasyRegex, settings::{base::*, group::DEFAULT_GROUP}};
+    ///
+    /// let result = EasyRegex::new_section()
+    ///     .named_group("quote", "[\"']", &DEFAULT_GROUP)
+    ///     .word(&ONE_OR_MORE)
+    ///     .backreference("quote", &DEFAULT);
+    /// let regex = result.get_fancy_regex().unwrap();
+    /// assert_eq!(r#"(?P<quote>["'])\w+\k<quote>"#, regex.as_str());
+    /// assert!(regex.is_match("'word'").unwrap());
+    /// assert!(!regex.is_match("'word\"").unwrap());
+    /// # }
+    /// ```
+    pub fn backreference<'a, G: Into<GroupId<'a>>>(self, group: G, settings: &Settings) -> Self {
+        #[cfg(feature = "fancy-regex")]
+        {
+            let group = match group.into() {
+                GroupId::Index(index) => index.to_string(),
+                GroupId::Name(name) => name.to_string(),
+            };
+            self.push(settings.apply("backreference", Node::Backreference(group)))
+        }
+        #[cfg(not(feature = "fancy-regex"))]
+        {
+            let _ = (group.into(), settings);
+            self.push(vec![Node::Invalid(crate::EasyRegexError::RequiresFancyRegex {
+                method: "backreference",
+            })])
+        }
     }
 }
 
+fn group_kind(method: &'static str, group_settings: &GroupSettings) -> GroupKind {
+    if group_settings.is_non_capture {
+        GroupKind::NonCapturing
+    } else {
+        GroupKind::Capturing { method }
+    }
+}
+
+/// Builds the nodes of a group wrapping the given nodes.
+///
+/// Flags of a non-capturing group are merged into the group itself (e.g. **(?i:RegExp)**) to keep the output clear,
+/// otherwise they are put at the start of the group (e.g. **((?i)RegExp)**).
+fn group_nodes(
+    method: &'static str,
+    kind: GroupKind,
+    inner: Vec<Node>,
+    group_settings: &GroupSettings,
+) -> Vec<Node> {
+    let settings = &group_settings.other;
+
+    let group = match (settings.flags, kind) {
+        (Some(flags), GroupKind::NonCapturing) => Node::FlagScope {
+            flags,
+            nodes: inner,
+        },
+        (flags, kind) => {
+            let mut nodes = Vec::new();
+            if let Some(flags) = flags.filter(|flags| !flags.is_empty()) {
+                nodes.push(Node::Flags(flags));
+            }
+            nodes.extend(inner);
+            Node::Group { kind, nodes }
+        }
+    };
+    Settings {
+        flags: None,
+        ..*settings
+    }
+    .apply(method, group)
+}
+
 #[cfg(test)]
 mod tests {
     use self::EasyRegex;
     use super::*;
-    use crate::settings::group::{DEFAULT_GROUP, INSENSITIVE_GROUP, INSENSITIVE_NON_CAPTURE};
+    use crate::settings::{
+        base::DEFAULT,
+        group::{
+            DEFAULT_GROUP, INSENSITIVE_GROUP, INSENSITIVE_NON_CAPTURE, NIL_OR_MORE_GROUP_UNGREEDY,
+            ONE_OR_MORE_GROUP_UNGREEDY, UNICODE_NON_CAPTURE,
+        },
+    };
 
     #[test]
     fn group_works() {
         let initial_exp = EasyRegex::new("initial_");
         let result = initial_exp.group("group", &DEFAULT_GROUP);
-        assert_eq!("initial_(group)", result.0);
+        assert_eq!("initial_(group)", result.as_str());
     }
 
     #[test]
@@ -223,7 +436,7 @@ mod tests {
         };
 
         let result = initial_exp.group("group", &group_settings);
-        assert_eq!("^(?:group)?", result.0);
+        assert_eq!("^(?:group)?", result.as_str());
     }
 
     #[test]
@@ -242,6 +455,90 @@ mod tests {
             .get_regex()
             .unwrap();
         assert_eq!("^(?i:group)", result.as_str());
+
+        let result = EasyRegex::new_section().group(
+            "a",
+            &GroupSettings {
+                is_non_capture: true,
+                other: Settings {
+                    is_one_or_more: true,
+                    with_left_boundary: true,
+                    flags: Some(Flags::Insensitive.into()),
+                    ..Default::default()
+                },
+            },
+        );
+        assert_eq!("\\b(?i:a)+", result.as_str());
+        assert!(result.get_regex().unwrap().is_match("AaA"));
+    }
+
+    #[test]
+    fn with_flags_works() {
+        let result = EasyRegex::multiline()
+            .with_flags(
+                FlagSet::new()
+                    .with(Flags::Insensitive)
+                    .with(Flags::DotMatchNewLine)
+                    .with(Flags::SingleLine),
+                |section| {
+                    section
+                        .literal("begin", &DEFAULT)
+                        .with_flags(Flags::Sensitive, |section| section.raw(".+", &DEFAULT))
+                },
+            )
+            .end_of_line();
+        assert_eq!("(?m)(?is-m:begin(?-i:.+))$", result.as_str());
+        assert!(result.get_regex().unwrap().is_match("BEGIN\nend\nnext"));
+    }
+
+    #[test]
+    fn new_flags_group_works() {
+        let result = EasyRegex::new_section()
+            .raw("a+", &DEFAULT)
+            .into_swap_greed_group()
+            .raw("$", &DEFAULT)
+            .into_crlf_non_capturing()
+            .raw("\\w", &DEFAULT)
+            .into_no_unicode_group();
+        assert_eq!("((?-u)(?R:((?U)a+)$)\\w)", result.as_str());
+        assert!(result.get_regex().is_ok());
+
+        let result = EasyRegex::new_section().group("é", &UNICODE_NON_CAPTURE);
+        assert_eq!("(?u:é)", result.as_str());
+    }
+
+    #[test]
+    fn ungreedy_group_works() {
+        let result = EasyRegex::new_section()
+            .group("ab", &ONE_OR_MORE_GROUP_UNGREEDY)
+            .group("cd", &NIL_OR_MORE_GROUP_UNGREEDY)
+            .group("ef", &GroupSettings::grp_range_ungreedy(Some(2), Some(5)))
+            .group("gh", &GroupSettings::grp_exactly_ungreedy(3));
+        assert_eq!("(ab)+?(cd)*?(ef){2,5}?(gh){3}?", result.as_str());
+    }
+
+    #[test]
+    fn backreference_with_regex_fails() {
+        let result = EasyRegex::new(r"(\d)").backreference(1, &DEFAULT).get_regex();
+        #[cfg(not(feature = "fancy-regex"))]
+        let expected = crate::EasyRegexError::RequiresFancyRegex {
+            method: "backreference",
+        };
+        #[cfg(feature = "fancy-regex")]
+        let expected = crate::EasyRegexError::UnsupportedConstruct {
+            method: "backreference",
+            engine: "regex::Regex",
+        };
+        assert_eq!(expected, result.unwrap_err());
+    }
+
+    #[test]
+    fn group_of_works() {
+        let section = EasyRegex::insensitive().literal("a.b", &DEFAULT);
+        let result = EasyRegex::new_section()
+            .group_of(section.clone(), &INSENSITIVE_NON_CAPTURE)
+            .group_of(section, &DEFAULT_GROUP);
+        assert_eq!("(?i:(?i)a\\.b)((?i)a\\.b)", result.as_str());
     }
 
     #[test]
@@ -249,7 +546,7 @@ mod tests {
         let initial_exp = EasyRegex::new("group");
         let result = initial_exp.into_group(&DEFAULT);
 
-        assert_eq!("(group)", result.0);
+        assert_eq!("(group)", result.as_str());
     }
 
     ////////////////////////////////////////////////// ERRORS /////////////////////////////////////////////////////
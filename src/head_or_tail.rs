@@ -5,18 +5,35 @@
 //! and flag-related methods can only be used as the starting method and
 //! the others could be used in the middle or at the end of a method chain as well.
 
-use crate::EasyRegex;
+use crate::{
+    ast::{Assertion, Node},
+    char_class::{CharClass, IntoCharClass},
+    settings::{FlagSet, Flags},
+    EasyRegex,
+};
 
 impl EasyRegex {
     /// Creates an ```EasyRegex``` instance starting with the ```^``` character, asserts position at start of the string.
     pub fn start_of_line() -> Self {
-        EasyRegex("^".to_string())
+        EasyRegex::from_nodes(vec![Node::Assertion(Assertion::StartOfLine)])
     }
 
     /// Adds the alternation symbol ```|``` to the expression.
+    ///
+    /// Everything before it becomes the first branch and everything chained after it the second one.
+    /// To choose between a few sub-patterns in the middle of a pattern, use the
+    /// [`alternation`](../struct.EasyRegex.html#method.alternation) or [`one_of`](../struct.EasyRegex.html#method.one_of) methods instead.
     pub fn or(self) -> Self {
-        let result = format!("{}|", self.0);
-        EasyRegex(result)
+        let mut nodes = self.0;
+        let mut branches = match nodes.pop() {
+            Some(Node::Alternation(branches)) if nodes.is_empty() => branches,
+            last => {
+                nodes.extend(last);
+                vec![nodes]
+            }
+        };
+        branches.push(Vec::new());
+        EasyRegex::from_nodes(vec![Node::Alternation(branches)])
     }
 
     /// Creates a list having ```^``` at the beginning.
@@ -30,51 +47,108 @@ impl EasyRegex {
     /// assert_eq!("[^abc]", result.get_regex().unwrap().as_str());
     /// ```
     pub fn not(self, expression: &str) -> Self {
-        let result = format!("{}[^{}]", self.0, expression);
-        EasyRegex(result)
+        let class = CharClass::new().raw(expression).negate();
+        self.push(vec![class.into_node("not")])
+    }
+
+    /// Same as the ```not``` method taking a [`CharClass`](../char_class/struct.CharClass.html).
+    ///
+    /// A negated class given to it becomes a normal list again.
+    pub fn not_class<C: IntoCharClass>(self, class: C) -> Self {
+        let class = class.into_char_class().negate();
+        self.push(vec![class.into_node("not_class")])
     }
 
     /// Adds one space character i.e. " " to the previous expression.
     pub fn literal_space(self) -> Self {
-        let result = format!("{} ", self.0);
-        EasyRegex(result)
+        self.push(vec![Node::Raw {
+            expression: " ".to_string(),
+            method: "literal_space",
+        }])
     }
 
     /// Adds the ending pattern ```$```, asserts position at the end of the string.
     pub fn end_of_line(self) -> Self {
-        let result = format!("{}$", self.0);
-        EasyRegex(result)
+        self.push(vec![Node::Assertion(Assertion::EndOfLine)])
+    }
+
+    /// Creates an ```EasyRegex``` instance starting with a set of flags, e.g. ```(?is-m)```.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::{FlagSet, Flags}};
+    ///
+    /// let result = EasyRegex::flags(Flags::Insensitive | Flags::DotMatchNewLine);
+    /// assert_eq!("(?is)", result.as_str());
+    ///
+    /// let result = EasyRegex::flags(FlagSet::new().multi_line(true).crlf(true)).raw("^a$", &Default::default());
+    /// assert!(result.get_regex().unwrap().is_match("b\r\na\r\n"));
+    /// ```
+    pub fn flags<S: Into<FlagSet>>(flags: S) -> Self {
+        let flags = flags.into();
+        if flags.is_empty() {
+            EasyRegex::new_section()
+        } else {
+            EasyRegex::from_nodes(vec![Node::Flags(flags)])
+        }
     }
 
     /// Creates an ```EasyRegex``` instance starting with the ```(?i)``` flag.
     pub fn insensitive() -> Self {
-        EasyRegex("(?i)".to_string())
+        EasyRegex::from_nodes(vec![Node::Flags(Flags::Insensitive.into())])
     }
 
     /// Creates an ```EasyRegex``` instance starting with the ```(?m)``` flag.
     pub fn multiline() -> Self {
-        EasyRegex("(?m)".to_string())
+        EasyRegex::from_nodes(vec![Node::Flags(Flags::Multiline.into())])
     }
 
     /// Creates an ```EasyRegex``` instance starting with the ```(?s)``` flag.
     pub fn dot_match_newline() -> Self {
-        EasyRegex("(?s)".to_string())
+        EasyRegex::from_nodes(vec![Node::Flags(Flags::DotMatchNewLine.into())])
     }
 
     /// Creates an ```EasyRegex``` instance starting with the ```(?x)``` flag.
     pub fn ignore_whitespace() -> Self {
-        EasyRegex("(?x)".to_string())
+        EasyRegex::from_nodes(vec![Node::Flags(Flags::IgnoreWhitespace.into())])
+    }
+
+    /// Creates an ```EasyRegex``` instance starting with the ```(?U)``` flag, swapping greedy and ungreedy quantifiers.
+    pub fn swap_greed() -> Self {
+        EasyRegex::from_nodes(vec![Node::Flags(Flags::SwapGreed.into())])
+    }
+
+    /// Creates an ```EasyRegex``` instance starting with the ```(?R)``` flag, treating ```\r\n``` as a line terminator in multi-line mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::base::{MULTILINE, ONE_OR_MORE}};
+    ///
+    /// let result = EasyRegex::crlf().raw("^", &MULTILINE).word(&ONE_OR_MORE).end_of_line();
+    /// let regex = result.get_regex().unwrap();
+    /// assert_eq!(r"(?R)(?m)^\w+$", regex.as_str());
+    /// assert_eq!(2, regex.find_iter("first\r\nsecond\r\n").count());
+    /// ```
+    pub fn crlf() -> Self {
+        EasyRegex::from_nodes(vec![Node::Flags(Flags::Crlf.into())])
+    }
+
+    /// Creates an ```EasyRegex``` instance starting with the ```(?-u)``` flag, making classes such as ```\w``` match ASCII only.
+    pub fn no_unicode() -> Self {
+        EasyRegex::from_nodes(vec![Node::Flags(Flags::NoUnicode.into())])
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{EasyRegex, settings::base::DEFAULT};
+    use crate::{EasyRegex, EasyRegexError, settings::base::DEFAULT};
 
     #[test]
     fn end_of_line_works() {
         let result = EasyRegex::new("abc").end_of_line();
-        assert_eq!("abc$", result.0);
+        assert_eq!("abc$", result.as_str());
     }
 
     #[test]
@@ -84,6 +158,16 @@ mod tests {
             .or()
             .literal("efg", &DEFAULT)
             .into_list(&DEFAULT);
-        assert_eq!("[abc|efg]", result.0);
+        assert_eq!("[abc|efg]", result.as_str());
+        assert_eq!(
+            EasyRegexError::AlternationInList { method: "into_list" },
+            result.get_regex().unwrap_err()
+        );
+
+        let result = EasyRegex::new_section()
+            .literal("abc", &DEFAULT)
+            .or()
+            .literal("efg", &DEFAULT);
+        assert!(result.get_regex().unwrap().is_match("efg"));
     }
-}
\ No newline at end of file
+}
@@ -1,5 +1,7 @@
 //! Helper methods for Persian letters.
 
+use crate::error::EasyRegexError;
+
 #[allow(dead_code)]
 
 struct LettersIndices<'a> {
@@ -285,31 +287,29 @@ lazy_static! {
 /// let result = create_persian_pattern(("ا"), ("ر"));
 /// assert_eq!("ابپتثجچحخدذر", result.unwrap());
 /// ```
-pub fn create_persian_pattern<'a>(from: &'a str, to: &'a str) -> Result<String, String> {
-    if let Some(found_from_idx) = LETTERS.iter().position(|l| l.letter == from) {
-        if let Some(found_to_idx) = LETTERS.iter().position(|l| l.letter == to) {
-            if found_from_idx > found_to_idx {
-                let err_msg = format!(
-                    "letter '{}' is after letter '{}', consider swapping them",
-                    &from, &to
-                );
-                Err(err_msg)
-            } else {
-                let found_slice = &LETTERS[found_from_idx..found_to_idx + 1];
-                let mut result = String::new();
-                found_slice.iter().for_each(|l| {
-                    result.push_str(l.index);
-                });
+pub fn create_persian_pattern<'a>(from: &'a str, to: &'a str) -> Result<String, EasyRegexError> {
+    let position = |letter: &str| {
+        LETTERS
+            .iter()
+            .position(|l| l.letter == letter)
+            .ok_or_else(|| EasyRegexError::InvalidLetter(letter.to_string()))
+    };
+    let found_from_idx = position(from)?;
+    let found_to_idx = position(to)?;
 
-                Ok(result)
-            }
-        } else {
-            let err_msg = format!("letter '{}' is not valid", &to);
-            Err(err_msg)
-        }
+    if found_from_idx > found_to_idx {
+        Err(EasyRegexError::LettersOrder {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
     } else {
-        let err_msg = format!("letter '{}' is not valid", &from);
-        Err(err_msg)
+        let found_slice = &LETTERS[found_from_idx..found_to_idx + 1];
+        let mut result = String::new();
+        found_slice.iter().for_each(|l| {
+            result.push_str(l.index);
+        });
+
+        Ok(result)
     }
 }
 
@@ -326,13 +326,13 @@ mod tests {
     #[test]
     fn create_persian_pattern_wrong_from_input() {
         let result = create_persian_pattern("d", "م");
-        assert_eq!("letter 'd' is not valid", result.unwrap_err())
+        assert_eq!("letter 'd' is not valid", result.unwrap_err().to_string())
     }
 
     #[test]
     fn create_persian_pattern_wrong_to_input() {
         let result = create_persian_pattern("ا", "d");
-        assert_eq!("letter 'd' is not valid", result.unwrap_err())
+        assert_eq!("letter 'd' is not valid", result.unwrap_err().to_string())
     }
 
     #[test]
@@ -340,7 +340,7 @@ mod tests {
         let result = create_persian_pattern("خ", "ا");
         assert_eq!(
             "letter 'خ' is after letter 'ا', consider swapping them",
-            result.unwrap_err()
+            result.unwrap_err().to_string()
         );
     }
 }
@@ -7,21 +7,72 @@
 //! the [`new_section`](struct.EasyRegex.html#method.new_section)
 //! method needs no input and basically creates an empty string to write the intented expressions by method chaining.
 //! To take the prepared regex out of the chain, the last method will be [`get_regex`](struct.EasyRegex.html#method.get_regex) 
-//! which outputs a ```Result``` including a regex of type ```Regex``` or an ```Error```.
+//! which outputs a ```Result``` including a regex of type ```Regex``` or an [`EasyRegexError`](error/enum.EasyRegexError.html).
 //! The [`get_regex`](struct.EasyRegex.html#method.get_regex) will in fact use 
 //! the [`RegexBuilder::new`](https://docs.rs/regex/latest/regex/struct.RegexSetBuilder.html#method.new)
 //! and [`RegexBuilder::build`](https://docs.rs/regex/latest/regex/struct.RegexSetBuilder.html#method.build) methods of
 //! the [regex](https://crates.io/crates/regex) crate.
+//! The [`captures_layout`](struct.EasyRegex.html#method.captures_layout) method lists the capture groups of the pattern,
+//! and [`get_regex_with_layout`](struct.EasyRegex.html#method.get_regex_with_layout) returns them along with the regex.
+//! Builder options such as ```size_limit``` or ```crlf``` could be set by [`get_regex_with`](struct.EasyRegex.html#method.get_regex_with) instead.
+//! To compile into another regex type such as ```regex::bytes::Regex```, [`compile`](struct.EasyRegex.html#method.compile)
+//! takes any engine implementing the [`RegexEngine`](engine::RegexEngine) trait.
+//! Input which isn't valid UTF-8 could be searched by [`get_bytes_regex`](struct.EasyRegex.html#method.get_bytes_regex),
+//! along with the byte matching methods of the [`bytes`](bytes/index.html) module.
+//! Many sections could be matched in a single pass by collecting them into an [`EasyRegexSet`](set::EasyRegexSet).
+//! To look at the pattern or compile it more than once without consuming the chain,
+//! [`as_str`](struct.EasyRegex.html#method.as_str) and [`to_regex`](struct.EasyRegex.html#method.to_regex) could be used.
+//!
+//! By default, quantifiers are appended to expressions as they are, so ```abc``` with a one-or-more setting becomes ```abc+```.
+//! Calling [`atomic_quantifiers`](struct.EasyRegex.html#method.atomic_quantifiers) on a chain wraps its expressions made of more than one atom
+//! into a non-capturing group first, making it ```(?:abc)+```. Single atoms such as ```\d```, ```[a-z]``` or ```a``` stay unwrapped.
+//!
+//! The regex crate doesn't support backreferences and lookarounds, so methods such as [`backreference`](struct.EasyRegex.html#method.backreference)
+//! and those of the [`lookaround`](lookaround/index.html) module
+//! need the ```fancy-regex``` feature, which adds [`get_fancy_regex`](struct.EasyRegex.html#method.get_fancy_regex)
+//! to compile the pattern with the [fancy-regex](https://crates.io/crates/fancy-regex) crate instead.
+//! Without the feature, ```get_regex``` reports them as ```EasyRegexError::RequiresFancyRegex```.
 
-use regex::{Regex, Error};
+use std::{
+    fmt::{self, Display, Formatter},
+    mem,
+    ops::{Add, AddAssign},
+    sync::OnceLock,
+};
 
+use regex::Regex;
+
+use crate::{ast::Node, compile::CompileOptions};
+pub use crate::error::EasyRegexError;
+
+mod ast;
+pub mod captures;
+pub mod compile;
+pub mod engine;
+pub mod set;
+#[cfg(feature = "fancy-regex")]
+pub mod fancy;
+pub mod error;
 pub mod literal;
 pub mod group;
+pub mod lookaround;
 pub mod list;
+pub mod char_class;
+pub mod alternation;
+pub mod bytes;
 pub mod settings;
+// Lints of the original collection tests and helpers, left as they are.
+#[allow(
+    clippy::four_forward_slashes,
+    clippy::needless_borrow,
+    clippy::bool_assert_comparison,
+    clippy::useless_conversion
+)]
 pub mod collection;
 pub mod head_or_tail;
 pub mod metacharacters;
+pub mod unicode;
+#[allow(clippy::single_char_add_str, clippy::empty_line_after_outer_attr)]
 pub mod helpers;
 
 #[macro_use]
@@ -29,23 +80,154 @@ extern crate lazy_static;
 
 #[derive(Debug, Clone)]
 /// Main struct includes methods to be chained together in order to create a regular expression.
-pub struct EasyRegex(String);
+///
+/// It holds the pattern as a tree of nodes along with its textual form, which is rendered once on first use.
+pub struct EasyRegex(Vec<Node>, OnceLock<String>);
 
 impl EasyRegex {
     /// Creates an ```EasyRegex``` instance, having initial raw pattern.
     pub fn new(raw: &str) -> Self {
-        EasyRegex(raw.to_string())
+        EasyRegex::from_nodes(vec![Node::Raw {
+            expression: raw.to_string(),
+            method: "new",
+        }])
     }
 
     /// Creates an empty ```EasyRegex``` instance, useful for start of a pattern.
     pub fn new_section() -> Self {
-        EasyRegex(String::new())
+        EasyRegex::from_nodes(Vec::new())
     }
 
     /// Retrieves the prepared regular expression as a ```Result```.
-    pub fn get_regex(self) -> Result<Regex, Error> {
-        let regex = regex::RegexBuilder::new(&self.0);
-        regex.build()
+    ///
+    /// Misuse of any chained method (e.g. a range whose start is greater than its end) is reported
+    /// before compiling, otherwise errors of the regex crate are wrapped into ```EasyRegexError::Regex```.
+    pub fn get_regex(self) -> Result<Regex, EasyRegexError> {
+        self.to_regex()
+    }
+
+    /// Same as ```get_regex``` without consuming the instance, so it could be compiled more than once.
+    pub fn to_regex(&self) -> Result<Regex, EasyRegexError> {
+        self.to_regex_with(&CompileOptions::default())
+    }
+
+    /// Retrieves the prepared pattern as a string slice without compiling it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::base::ONE_OR_MORE};
+    ///
+    /// let result = EasyRegex::start_of_line().digit(&ONE_OR_MORE);
+    /// assert_eq!(r"^\d+", result.as_str());
+    /// assert_eq!(r"^\d+", format!("{}", result));
+    /// ```
+    pub fn as_str(&self) -> &str {
+        self.1.get_or_init(|| ast::render(&self.0))
+    }
+
+    /// Turns the instance into its prepared pattern without compiling it.
+    pub fn into_string(self) -> String {
+        let EasyRegex(nodes, rendered) = self;
+        rendered.into_inner().unwrap_or_else(|| ast::render(&nodes))
+    }
+
+    /// Appends another ```EasyRegex``` section to the end of the chain.
+    ///
+    /// Flags set at the top level of either side and its alternations are kept inside it
+    /// by wrapping that side into a non-capturing group, so the rest of the pattern is not affected by them.
+    /// An instance made of flags only, e.g. ```EasyRegex::insensitive()```, applies them to the appended section,
+    /// which is written as **(?flags:RegExp)**.
+    /// The same could be done by the ```+``` and ```+=``` operators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::base::{DEFAULT, ONE_OR_MORE}};
+    ///
+    /// let scheme = EasyRegex::insensitive().raw("https?", &DEFAULT).or().raw("ftp", &DEFAULT);
+    /// let host = EasyRegex::new_section().literal("://", &DEFAULT).word(&ONE_OR_MORE);
+    ///
+    /// let result = EasyRegex::start_of_line().then(scheme) + host;
+    /// assert_eq!(r"^(?:(?i)https?|ftp)://\w+", result.as_str());
+    ///
+    /// let result = EasyRegex::multiline() + EasyRegex::start_of_line().word(&ONE_OR_MORE);
+    /// assert_eq!(r"(?m:^\w+)", result.as_str());
+    /// ```
+    pub fn then(self, section: EasyRegex) -> Self {
+        match ast::only_flags(&self.0) {
+            Some(flags) if flags.is_empty() => EasyRegex::from_nodes(ast::enclose(section.0)),
+            Some(flags) => EasyRegex::from_nodes(vec![Node::FlagScope {
+                flags,
+                nodes: section.0,
+            }]),
+            None => {
+                let chain = EasyRegex::from_nodes(ast::enclose(self.0));
+                chain.push(ast::enclose(section.0))
+            }
+        }
+    }
+
+    /// Wraps the quantified expressions of the chain made of more than one atom into a non-capturing group,
+    /// so the quantifier repeats all of them, e.g. ```(?:abc)+``` instead of ```abc+```.
+    ///
+    /// Only the expressions added so far are wrapped, so it is usually called at the end of the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::base::*};
+    ///
+    /// let result = EasyRegex::new_section()
+    ///     .literal("ab", &ONE_OR_MORE)
+    ///     .digit(&OPTIONAL)
+    ///     .atomic_quantifiers();
+    /// assert_eq!(r"(?:ab)+\d?", result.get_regex().unwrap().as_str());
+    /// ```
+    pub fn atomic_quantifiers(self) -> Self {
+        let mut nodes = self.0;
+        ast::wrap_quantified(&mut nodes);
+        EasyRegex::from_nodes(nodes)
+    }
+
+    /// Creates an instance out of a pattern tree.
+    pub(crate) fn from_nodes(nodes: Vec<Node>) -> Self {
+        EasyRegex(nodes, OnceLock::new())
+    }
+
+    /// Appends nodes to the end of the chain.
+    pub(crate) fn push(self, nodes: Vec<Node>) -> Self {
+        let mut sequence = self.0;
+        ast::append(&mut sequence, nodes);
+        EasyRegex::from_nodes(sequence)
+    }
+}
+
+impl From<&str> for EasyRegex {
+    /// Same as the ```new``` method, inserting the expression as it is.
+    fn from(raw: &str) -> Self {
+        EasyRegex::new(raw)
+    }
+}
+
+impl Add for EasyRegex {
+    type Output = EasyRegex;
+
+    fn add(self, section: EasyRegex) -> Self::Output {
+        self.then(section)
+    }
+}
+
+impl AddAssign for EasyRegex {
+    fn add_assign(&mut self, section: EasyRegex) {
+        let chain = mem::replace(self, EasyRegex::new_section());
+        *self = chain.then(section);
+    }
+}
+
+impl Display for EasyRegex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
     }
 }
 
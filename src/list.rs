@@ -5,7 +5,12 @@
 //! They both use the [`literal`](../struct.EasyRegex.html#method.literal) method internally and in some ways are similar 
 //! to the [`group`](../struct.EasyRegex.html#method.group) and [`into_group`](../struct.EasyRegex.html#method.into_group) methods.
 
-use crate::{settings::Settings, EasyRegex};
+use crate::{
+    ast::{self, Node},
+    char_class::{CharClass, IntoCharClass},
+    settings::Settings,
+    EasyRegex, EasyRegexError,
+};
 
 impl EasyRegex {
     /// Creates a list of expressions.
@@ -13,25 +18,57 @@ impl EasyRegex {
     /// This method takes an expression (a segment of entire pattern) followed
     /// by a set of settings (```Settings``` struct) that will be concatenated/inserted to the expression itself,
     /// outputing the previous pattern followed by this list.
+    /// The expression is inserted as it is, use [`list_class`](#method.list_class) instead to have it escaped.
     ///
     /// # Examples
     ///
     /// ```
     /// use easy_regex::{EasyRegex, settings::base::DEFAULT};
-
+    ///
     /// let result = EasyRegex::new_section().list("some_list", &DEFAULT);
     /// assert_eq!("[some_list]", result.get_regex().unwrap().as_str());
     /// ```
     pub fn list(self, expression: &str, settings: &Settings) -> EasyRegex {
-        let mut final_result = expression.to_string();
-        final_result = format!("[{}]", final_result);
+        let list = CharClass::new().raw(expression).into_node("list");
+        self.push(settings.apply("list", list))
+    }
 
-        let final_result = self.literal(&final_result, &settings);
-        final_result
+    /// Same as the ```list``` method taking a [`CharClass`](../char_class/struct.CharClass.html), which escapes its characters itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, char_class::CharClass, settings::base::DEFAULT};
+    ///
+    /// let result = EasyRegex::new_section().list_class(CharClass::new().chars("a-z"), &DEFAULT);
+    /// assert_eq!(r"[a\-z]", result.get_regex().unwrap().as_str());
+    /// ```
+    pub fn list_class<C: IntoCharClass>(self, class: C, settings: &Settings) -> EasyRegex {
+        let list = class.into_char_class().into_node("list_class");
+        self.push(settings.apply("list_class", list))
+    }
+
+    /// Same as the ```list``` method taking another ```EasyRegex``` section as the content of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, collection::LOWER_CASE, settings::base::{DEFAULT, ONE_OR_MORE}};
+    ///
+    /// let lower_or_dash = EasyRegex::new_section().raw(LOWER_CASE, &DEFAULT).raw("-", &DEFAULT);
+    /// let result = EasyRegex::new_section().list_of(lower_or_dash, &ONE_OR_MORE);
+    /// assert_eq!("[a-z-]+", result.get_regex().unwrap().as_str());
+    /// ```
+    pub fn list_of(self, section: EasyRegex, settings: &Settings) -> EasyRegex {
+        self.push(list_nodes("list_of", section.0, settings))
     }
 
     /// Turns the previous expressions into a list.
     ///
+    /// A ```CharClass``` turned into an ```EasyRegex``` section is nested in the list, making a union of both.
+    /// Alternations (see [`alternation`](../struct.EasyRegex.html#method.alternation)) can't be turned into a list
+    /// as their ```|``` would be matched literally, so doing so is reported as an error by the ```get_regex``` method.
+    ///
     /// # Examples
     ///
     /// ```
@@ -41,12 +78,27 @@ impl EasyRegex {
     /// assert_eq!(r"[a-z]??", result.get_regex().unwrap().as_str());
     /// ```
     pub fn into_list(self, settings: &Settings) -> EasyRegex {
-        let raw_result = format!("[{}]", self.0);
-        let final_result = EasyRegex::new_section().literal(&raw_result, &settings);
-        final_result
+        EasyRegex::from_nodes(list_nodes("into_list", self.0, settings))
     }
 }
 
+/// Builds a list out of the given nodes, rejecting alternations.
+fn list_nodes(method: &'static str, items: Vec<Node>, settings: &Settings) -> Vec<Node> {
+    let has_alternation = ast::has_alternation(&items);
+    let mut nodes = settings.apply(
+        method,
+        Node::Class {
+            negated: false,
+            items,
+            method,
+        },
+    );
+    if has_alternation {
+        nodes.push(Node::Invalid(EasyRegexError::AlternationInList { method }));
+    }
+    nodes
+}
+
 #[cfg(test)]
 mod tests {
     use self::EasyRegex;
@@ -57,7 +109,7 @@ mod tests {
     fn list_works() {
         let initial_exp = EasyRegex::new("initial");
         let result = initial_exp.list("abcd", &DEFAULT);
-        assert_eq!(result.0, "initial[abcd]");
+        assert_eq!(result.as_str(), "initial[abcd]");
     }
 
     #[test]
@@ -71,7 +123,11 @@ mod tests {
                 ..Default::default()
             },
         );
-        assert_eq!("initial[abcd]*+", result.get_regex().unwrap().as_str());
+        assert_eq!("initial[abcd]*+", result.as_str());
+        assert_eq!(
+            "list: conflicting quantifiers '*', '+' are set",
+            result.get_regex().unwrap_err().to_string()
+        );
     }
 
     #[test]
@@ -80,11 +136,19 @@ mod tests {
             "list",
             &Settings {
                 range: Some((Some(2), None)),
-                flags: Some(Flags::Insensitive),
+                flags: Some(Flags::Insensitive.into()),
                 ..Default::default()
             },
         );
 
-        assert_eq!("(?i)[list]{2,}", result.0);
+        assert_eq!("(?i)[list]{2,}", result.as_str());
+    }
+
+    #[test]
+    fn list_of_lazy_static_collection_works() {
+        use crate::collection::IPV6;
+
+        let result = EasyRegex::new_section().list(&IPV6, &DEFAULT).not(&IPV6);
+        assert_eq!(format!("[{}][^{}]", *IPV6, *IPV6), result.as_str());
     }
 }
@@ -1,11 +1,13 @@
 //! Creates expressions to match literal characters.
 //! 
 //! The most basic way of writing regex using this crate is by calling the [`literal`](../struct.EasyRegex.html#method.literal) method.
-//! As the name implies, it's for creating regular expressions that should match characters literally.
+//! As the name implies, it's for creating regular expressions that should match characters literally,
+//! so any metacharacter in its expression is escaped.
+//! To insert a pattern as it is, the [`raw`](../struct.EasyRegex.html#method.raw) method should be used instead.
 //! It cannot be used as the very first method but it is the backbone of other important methods (e.g. [`group`](../struct.EasyRegex.html#method.group) method).
 //! It takes an expression and a set of settings (special characters, flags etc.) and outputs an ```EasyRegex``` instance.
 
-use crate::{settings::*, EasyRegex};
+use crate::{ast::Node, settings::*, EasyRegex};
 
 impl EasyRegex {
     /// Creates a literal regular expression.
@@ -13,85 +15,47 @@ impl EasyRegex {
     /// This method takes an expression (a segment of entire pattern) followed
     /// by a set of settings (```Settings``` struct) that will be concatenated/inserted to the expression itself,
     /// outputing the previous pattern followed by this prepared regular expression.
+    /// Metacharacters of the expression are escaped the same way as [`regex::escape`](https://docs.rs/regex/latest/regex/fn.escape.html) does.
     ///
     /// # Examples
     ///
     /// ```
     /// use easy_regex::{EasyRegex, settings::base::DEFAULT};
     /// 
-    /// let result = EasyRegex::new_section().literal("some expression", &DEFAULT);
-    /// assert_eq!("some expression", result.get_regex().unwrap().as_str());
+    /// let result = EasyRegex::new_section().literal("1.5 expression", &DEFAULT);
+    /// assert_eq!(r"1\.5 expression", result.get_regex().unwrap().as_str());
     /// ```
     pub fn literal(self, expression: &str, settings: &Settings) -> EasyRegex {
-        let mut final_result = expression.to_string();
-
-        if let Some(flag) = settings.flags {
-            let mut stringified_flag = flag.as_str().to_string();
-            stringified_flag = format!("({})", stringified_flag);
-            stringified_flag.push_str(expression);
-            final_result = stringified_flag;
-        }
-        if settings.with_left_boundary {
-            let mut boundary = String::from("\\b");
-            boundary.push_str(&final_result);
-            final_result = boundary;
-        }
-        if settings.with_left_non_boundary {
-            let mut boundary = String::from("\\B");
-            boundary.push_str(&final_result);
-            final_result = boundary;
-        }
-        if settings.range.is_some()
-            && (settings.range.unwrap().0.is_some() || settings.range.unwrap().1.is_some())
-        {
-            let numbers = settings.range.unwrap();
-
-            final_result.push_str("{");
-
-            if let Some(start_range) = numbers.0 {
-                final_result.push_str(&start_range.to_string());
-            }
-
-            final_result.push_str(",");
-
-            if let Some(end_range) = numbers.1 {
-                final_result.push_str(&end_range.to_string());
-            }
-
-            final_result.push_str("}");
-        }
-        if let Some(number) = settings.exactly {
-            final_result.push_str("{");
-            final_result.push_str(&number.to_string());
-            final_result.push_str("}");
-        }
-        if settings.is_nil_or_more {
-            final_result.push_str("*");
-        }
-        if settings.is_one_or_more {
-            final_result.push_str("+");
-        }
-        if settings.is_optional {
-            final_result.push_str("?");
-        }
-        if settings.is_optional_ungreedy {
-            final_result.push_str("??");
-        }
-        if settings.with_right_boundary {
-            final_result.push_str("\\b");
-        }
-        if settings.with_right_non_boundary {
-            final_result.push_str("\\B");
-        }
-
-        final_result = format!("{}{}", &self.0, final_result);
-        EasyRegex(final_result)
+        let nodes = settings.apply("literal", Node::Literal(expression.to_string()));
+        self.push(nodes)
+    }
+
+    /// Creates a raw regular expression.
+    ///
+    /// Same as the ```literal``` method except that the expression is inserted as it is,
+    /// so it could contain any regex syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::base::ONE_OR_MORE};
+    /// 
+    /// let result = EasyRegex::new_section().raw(r"\d", &ONE_OR_MORE);
+    /// assert_eq!(r"\d+", result.get_regex().unwrap().as_str());
+    /// ```
+    pub fn raw(self, expression: &str, settings: &Settings) -> EasyRegex {
+        let node = Node::Raw {
+            expression: expression.to_string(),
+            method: "raw",
+        };
+        let nodes = settings.apply("raw", node);
+        self.push(nodes)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::settings::base::*;
+    use crate::{settings::base::*, EasyRegexError};
 
     use self::EasyRegex;
     use super::*;
@@ -100,14 +64,14 @@ mod tests {
     fn literal_exp_works() {
         let initial_exp = EasyRegex::new("initial_");
         let result = initial_exp.literal("abcd", &DEFAULT);
-        assert_eq!(result.0, "initial_abcd");
+        assert_eq!(result.as_str(), "initial_abcd");
     }
 
     #[test]
     fn literal_exp_optional_works() {
         let initial_exp = EasyRegex::new("initial_");
         let result = initial_exp.literal("abcd", &OPTIONAL);
-        assert_eq!(result.0, "initial_abcd?");
+        assert_eq!(result.as_str(), "initial_abcd?");
     }
 
     #[test]
@@ -121,7 +85,91 @@ mod tests {
                 ..Default::default()
             },
         );
-        assert_eq!(result.0, "initial_abcd{,2}?");
+        assert_eq!(result.as_str(), "initial_abcd{0,2}?");
+    }
+
+    #[test]
+    fn literal_exp_escape_works() {
+        let initial_exp = EasyRegex::new("initial_");
+        let result = initial_exp.literal("1.5(x)", &ONE_OR_MORE);
+        assert_eq!(result.as_str(), r"initial_1\.5\(x\)+");
+    }
+
+    #[test]
+    fn raw_exp_works() {
+        let initial_exp = EasyRegex::new("initial_");
+        let result = initial_exp.raw("1.5(x)", &DEFAULT);
+        assert_eq!(result.as_str(), "initial_1.5(x)");
+    }
+
+    #[test]
+    fn literal_exp_quantifier_works() {
+        let initial_exp = EasyRegex::new("initial_");
+        let result = initial_exp
+            .raw("\\d", &Quantifier::RangeUngreedy(2, 5).into())
+            .literal("-", &Quantifier::OptionalUngreedy.into());
+        assert_eq!(result.as_str(), "initial_\\d{2,5}?\\-??");
+    }
+
+    #[test]
+    fn literal_exp_ungreedy_works() {
+        let initial_exp = EasyRegex::new("initial_");
+        let result = initial_exp
+            .raw("a", &Settings::range_ungreedy(Some(2), None))
+            .raw("b", &Settings::exactly_ungreedy(4))
+            .raw("c", &ONE_OR_MORE_UNGREEDY);
+        assert_eq!("initial_a{2,}?b{4}?c+?", result.get_regex().unwrap().as_str());
+    }
+
+    #[test]
+    fn literal_exp_at_most_works() {
+        let result = EasyRegex::new_section()
+            .literal(
+                "a",
+                &Settings {
+                    quantifier: Some(Quantifier::AtMost(3)),
+                    ..Default::default()
+                },
+            )
+            .raw("b", &Settings::range(None, Some(2)))
+            .raw("c", &Quantifier::AtMostUngreedy(1).into());
+        let regex = result.get_regex().unwrap();
+        assert_eq!("a{0,3}b{0,2}c{0,1}?", regex.as_str());
+        assert_eq!("aabb", regex.find("aabbc").unwrap().as_str());
+    }
+
+    #[test]
+    fn literal_exp_wide_range_works() {
+        let initial_exp = EasyRegex::new("initial_");
+        let result = initial_exp
+            .raw("a", &Settings::range(Some(0), Some(1000)))
+            .raw("b", &Settings::range(Some(1), Some(4096)))
+            .raw("c", &Settings::exactly(70000));
+        assert_eq!(
+            "initial_a{0,1000}b{1,4096}c{70000}",
+            result.get_regex().unwrap().as_str()
+        );
+    }
+
+    #[test]
+    fn literal_exp_large_repetition_needs_size_limit() {
+        use crate::compile::CompileOptions;
+
+        let result = EasyRegex::new_section().raw("a", &Settings::exactly(100_000));
+        let small = CompileOptions {
+            size_limit: Some(1 << 16),
+            ..Default::default()
+        };
+        assert!(matches!(
+            result.to_regex_with(&small),
+            Err(EasyRegexError::Regex(regex::Error::CompiledTooBig(_)))
+        ));
+
+        let large = CompileOptions {
+            size_limit: Some(1 << 30),
+            ..Default::default()
+        };
+        assert_eq!("a{100000}", result.get_regex_with(&large).unwrap().as_str());
     }
 
     #[test]
@@ -135,13 +183,13 @@ mod tests {
                 ..Default::default()
             },
         );
-        assert_eq!(result.0, "initial_\\babcd\\b");
+        assert_eq!(result.as_str(), "initial_\\babcd\\b");
     }
 
     #[test]
     fn literal_exp_mixed_works() {
         let initial_exp = EasyRegex::new("initial_");
-        let result = initial_exp.literal(
+        let result = initial_exp.raw(
             "ab\\scd",
             &Settings {
                 is_optional: true,
@@ -151,13 +199,13 @@ mod tests {
                 ..Default::default()
             },
         );
-        assert_eq!(result.0, "initial_\\bab\\scd{,5}?\\b");
+        assert_eq!(result.as_str(), "initial_\\bab\\scd{0,5}?\\b");
     }
 
     #[test]
     fn literal_exp_works_with_range_and_start_and_plus() {
         let initial_exp = EasyRegex::new("initial_");
-        let result = initial_exp.literal(
+        let result = initial_exp.raw(
             "ab\\scd",
             &Settings {
                 is_nil_or_more: true,
@@ -166,13 +214,20 @@ mod tests {
             },
         );
 
-        assert_eq!("initial_ab\\scd{2,}*", result.get_regex().unwrap().as_str());
+        assert_eq!("initial_ab\\scd{2,}*", result.as_str());
+        assert_eq!(
+            EasyRegexError::ConflictingQuantifiers {
+                method: "raw",
+                quantifiers: vec![Quantifier::AtLeast(2), Quantifier::NilOrMore],
+            },
+            result.get_regex().unwrap_err()
+        );
     }
 
     #[test]
     fn literal_exp_works_with_star_and_plus() {
         let initial_exp = EasyRegex::new("initial_");
-        let result = initial_exp.literal(
+        let result = initial_exp.raw(
             "ab\\scd",
             &Settings {
                 is_nil_or_more: true,
@@ -181,13 +236,17 @@ mod tests {
             },
         );
 
-        assert_eq!("initial_ab\\scd*+", result.get_regex().unwrap().as_str());
+        assert_eq!("initial_ab\\scd*+", result.as_str());
+        assert_eq!(
+            "raw: conflicting quantifiers '*', '+' are set",
+            result.get_regex().unwrap_err().to_string()
+        );
     }
 
     #[test]
     fn literal_exp_works_with_range_and_exact() {
         let initial_exp = EasyRegex::new("initial_");
-        let result = initial_exp.literal(
+        let result = initial_exp.raw(
             "ab\\scd",
             &Settings {
                 range: Some((Some(2), Some(10))),
@@ -196,9 +255,46 @@ mod tests {
             },
         );
 
+        assert_eq!("initial_ab\\scd{2,10}{8}", result.as_str());
         assert_eq!(
-            "initial_ab\\scd{2,10}{8}",
-            result.get_regex().unwrap().as_str()
+            EasyRegexError::ConflictingQuantifiers {
+                method: "raw",
+                quantifiers: vec![Quantifier::Range(2, 10), Quantifier::Exactly(8)],
+            },
+            result.get_regex().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn literal_exp_invalid_range_fails() {
+        let result = EasyRegex::new_section()
+            .literal("a", &DEFAULT)
+            .literal("b", &Settings::range(Some(5), Some(2)));
+        assert_eq!(
+            EasyRegexError::InvalidRange {
+                method: "literal",
+                from: 5,
+                to: 2
+            },
+            result.get_regex().unwrap_err()
+        );
+
+        let result = EasyRegex::new_section().digit(&Settings::range(None, None));
+        assert_eq!(
+            EasyRegexError::EmptyRange { method: "digit" },
+            result.get_regex().unwrap_err()
         );
     }
+
+    #[test]
+    fn literal_exp_atomic_quantifier_works() {
+        let initial_exp = EasyRegex::new("initial_");
+        let result = initial_exp
+            .literal("abcd", &ONE_OR_MORE)
+            .raw("\\d", &OPTIONAL)
+            .literal("1.5", &Settings::exactly(2))
+            .atomic_quantifiers()
+            .literal("ef", &OPTIONAL);
+        assert_eq!(result.as_str(), "initial_(?:abcd)+\\d?(?:1\\.5){2}ef?");
+    }
 }
@@ -0,0 +1,133 @@
+//! Creates lookahead and lookbehind assertions.
+//!
+//! These methods check what comes after or before the current position without consuming it,
+//! which is handy for validation rules such as "contains a digit somewhere".
+//! Each one takes either an expression as ```&str```, inserted as it is, or another ```EasyRegex``` section.
+//!
+//! The regex crate doesn't support lookarounds, so they need the ```fancy-regex``` feature and
+//! the pattern has to be compiled by [`get_fancy_regex`](../struct.EasyRegex.html#method.get_fancy_regex).
+//! Otherwise ```get_regex``` reports ```EasyRegexError::RequiresFancyRegex``` naming the method.
+
+use crate::{
+    ast::{Lookaround, Node},
+    EasyRegex,
+};
+
+impl EasyRegex {
+    /// Asserts that the given section matches right after the current position **(?=RegExp)**.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "fancy-regex")] {
+    /// use easy_regex::{EasyRegex, settings::{base::*, Settings}};
+    ///
+    /// let has_digit = EasyRegex::new_section().raw(".", &NIL_OR_MORE).digit(&DEFAULT);
+    /// let result = EasyRegex::start_of_line()
+    ///     .followed_by(has_digit)
+    ///     .word(&Settings::range(Some(8), None))
+    ///     .end_of_line();
+    /// let regex = result.get_fancy_regex().unwrap();
+    /// assert_eq!(r"^(?=.*\d)\w{8,}$", regex.as_str());
+    /// assert!(regex.is_match("passw0rd").unwrap());
+    /// assert!(!regex.is_match("password").unwrap());
+    /// # }
+    /// ```
+    pub fn followed_by<S: Into<EasyRegex>>(self, section: S) -> Self {
+        self.lookaround(Lookaround::Ahead, section.into())
+    }
+
+    /// Asserts that the given section doesn't match right after the current position **(?!RegExp)**.
+    pub fn not_followed_by<S: Into<EasyRegex>>(self, section: S) -> Self {
+        self.lookaround(Lookaround::NegativeAhead, section.into())
+    }
+
+    /// Asserts that the given section matches right before the current position **(?<=RegExp)**.
+    ///
+    /// Like most backtracking engines, fancy-regex only accepts sections of a fixed length here.
+    pub fn preceded_by<S: Into<EasyRegex>>(self, section: S) -> Self {
+        self.lookaround(Lookaround::Behind, section.into())
+    }
+
+    /// Asserts that the given section doesn't match right before the current position **(?<!RegExp)**.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "fancy-regex")] {
+    /// use easy_regex::{EasyRegex, settings::base::*};
+    ///
+    /// let result = EasyRegex::new_section()
+    ///     .not_preceded_by(r"[-\d]")
+    ///     .digit(&ONE_OR_MORE)
+    ///     .literal("$", &DEFAULT);
+    /// let regex = result.get_fancy_regex().unwrap();
+    /// assert_eq!("12$", regex.find("-5$ or 12$").unwrap().unwrap().as_str());
+    /// # }
+    /// ```
+    pub fn not_preceded_by<S: Into<EasyRegex>>(self, section: S) -> Self {
+        self.lookaround(Lookaround::NegativeBehind, section.into())
+    }
+
+    #[cfg(feature = "fancy-regex")]
+    fn lookaround(self, kind: Lookaround, section: EasyRegex) -> Self {
+        self.push(vec![Node::Lookaround {
+            kind,
+            nodes: section.0,
+        }])
+    }
+
+    #[cfg(not(feature = "fancy-regex"))]
+    fn lookaround(self, kind: Lookaround, _section: EasyRegex) -> Self {
+        self.push(vec![Node::Invalid(
+            crate::EasyRegexError::RequiresFancyRegex {
+                method: kind.method(),
+            },
+        )])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{settings::base::*, EasyRegexError};
+
+    #[test]
+    fn lookaround_with_regex_fails() {
+        let result = EasyRegex::new_section()
+            .digit(&ONE_OR_MORE)
+            .not_followed_by("%")
+            .get_regex();
+        #[cfg(not(feature = "fancy-regex"))]
+        let expected = EasyRegexError::RequiresFancyRegex {
+            method: "not_followed_by",
+        };
+        #[cfg(feature = "fancy-regex")]
+        let expected = EasyRegexError::UnsupportedConstruct {
+            method: "not_followed_by",
+            engine: "regex::Regex",
+        };
+        assert_eq!(expected, result.unwrap_err());
+    }
+
+    #[cfg(feature = "fancy-regex")]
+    #[test]
+    fn lookaround_works() {
+        use crate::settings::{group::DEFAULT_GROUP, Settings};
+
+        let price = EasyRegex::new_section()
+            .digit(&ONE_OR_MORE)
+            .literal(".", &DEFAULT)
+            .digit(&Settings::exactly(2));
+        let result = EasyRegex::new_section()
+            .preceded_by(EasyRegex::new_section().literal("$", &DEFAULT))
+            .group_of(price, &DEFAULT_GROUP)
+            .not_followed_by(r"\d");
+        assert_eq!(r"(?<=\$)(\d+\.\d{2})(?!\d)", result.as_str());
+        let regex = result.get_fancy_regex().unwrap();
+        assert_eq!(
+            "4.50",
+            regex.captures("$4.501 or $4.50").unwrap().unwrap()[1].to_string()
+        );
+    }
+}
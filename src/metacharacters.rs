@@ -1,64 +1,158 @@
 //! Methods related to metacharacters.
+//!
+//! Besides the Unicode-aware ```\w```, ```\d``` and ```\s```, the ASCII classes of the regex crate (e.g. ```[[:alpha:]]```)
+//! are available through the [`ascii_class`](../struct.EasyRegex.html#method.ascii_class) method.
 
-use crate::{settings::Settings, EasyRegex};
+use crate::{ast::{Assertion, Node}, settings::Settings, EasyRegex};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// ASCII character classes, matching ASCII characters only whatever the ```u``` flag is.
+pub enum AsciiClass {
+    /// ```[0-9A-Za-z]```
+    Alnum,
+    /// ```[A-Za-z]```
+    Alpha,
+    /// ```[\x00-\x7F]```
+    Ascii,
+    /// ```[\t ]```
+    Blank,
+    /// ```[\x00-\x1F\x7F]```
+    Cntrl,
+    /// ```[0-9]```
+    Digit,
+    /// ```[!-~]```
+    Graph,
+    /// ```[a-z]```
+    Lower,
+    /// ```[ -~]```
+    Print,
+    /// ```[!-/:-@\[-`{-~]```
+    Punct,
+    /// ```[\t\n\v\f\r ]```
+    Space,
+    /// ```[A-Z]```
+    Upper,
+    /// ```[0-9A-Za-z_]```
+    Word,
+    /// ```[0-9A-Fa-f]```
+    Xdigit,
+}
+
+impl AsciiClass {
+    /// The name of the class, e.g. ```alpha```.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AsciiClass::Alnum => "alnum",
+            AsciiClass::Alpha => "alpha",
+            AsciiClass::Ascii => "ascii",
+            AsciiClass::Blank => "blank",
+            AsciiClass::Cntrl => "cntrl",
+            AsciiClass::Digit => "digit",
+            AsciiClass::Graph => "graph",
+            AsciiClass::Lower => "lower",
+            AsciiClass::Print => "print",
+            AsciiClass::Punct => "punct",
+            AsciiClass::Space => "space",
+            AsciiClass::Upper => "upper",
+            AsciiClass::Word => "word",
+            AsciiClass::Xdigit => "xdigit",
+        }
+    }
+
+    /// The class as an item of a bracketed list, e.g. ```[:alpha:]``` or ```[:^alpha:]```.
+    pub(crate) fn item(&self, method: &'static str, negated: bool) -> Node {
+        Node::Raw {
+            expression: format!("[:{}{}:]", if negated { "^" } else { "" }, self.as_str()),
+            method,
+        }
+    }
+}
 
 impl EasyRegex {
     /// Creates an ```EasyRegex``` instance starting with the ```\A``` character, asserts position at start of the string.
     pub fn only_the_beginning() -> Self {
-        EasyRegex("\\A".to_string())
+        EasyRegex::from_nodes(vec![Node::Assertion(Assertion::OnlyTheBeginning)])
     }
 
     /// Adds the ```\b``` metacharacter, asserts position at a word boundary.
     pub fn word_boundary(self) -> Self {
-        let result = format!("{}\\b", self.0);
-        EasyRegex(result)
+        self.push(vec![Node::Assertion(Assertion::WordBoundary)])
     }
 
     /// Adds the ```\w``` metacharacter, matches any word character [a-zA-Z0-9_].
     pub fn word(self, settings: &Settings) -> Self {
-        let result = self.literal("\\w", settings);
-        result
+        self.push(metacharacter("word", "\\w", settings))
     }
 
     /// Adds the ```\w``` metacharacter, matches any non-word character \[^a-zA-Z0-9_\].
     pub fn non_word(self, settings: &Settings) -> Self {
-        let result = self.literal("\\W", settings);
-        result
+        self.push(metacharacter("non_word", "\\W", settings))
     }
 
     /// Adds the ```\d``` metacharacter, matches digit character [0-9].
     pub fn digit(self, settings: &Settings) -> Self {
-        let result = self.literal("\\d", settings);
-        result
+        self.push(metacharacter("digit", "\\d", settings))
     }
 
     /// Adds the ```\D``` metacharacter, matches any non-digit character \[^0-9\].
     pub fn non_digit(self, settings: &Settings) -> Self {
-        let result = self.literal("\\D", settings);
-        result
+        self.push(metacharacter("non_digit", "\\D", settings))
     }
 
     /// Adds the ```\s``` metacharacter, matches any whitespace character [\r\n\t\f\v ].
     pub fn whitespace(self, settings: &Settings) -> Self {
-        let result = self.literal("\\s", settings);
-        result
+        self.push(metacharacter("whitespace", "\\s", settings))
     }
 
     /// Adds the ```\S``` metacharacter, matches any non-whitespace character \[^\r\n\t\f\v \].
     pub fn non_whitespace(self, settings: &Settings) -> Self {
-        let result = self.literal("\\S", settings);
-        result
+        self.push(metacharacter("non_whitespace", "\\S", settings))
+    }
+
+    /// Adds an ASCII class, e.g. ```[[:alpha:]]```.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, metacharacters::AsciiClass, settings::base::ONE_OR_MORE};
+    ///
+    /// let result = EasyRegex::new_section().ascii_class(AsciiClass::Xdigit, &ONE_OR_MORE);
+    /// let regex = result.get_regex().unwrap();
+    /// assert_eq!("[[:xdigit:]]+", regex.as_str());
+    /// assert!(regex.is_match("c0ffee"));
+    /// ```
+    pub fn ascii_class(self, class: AsciiClass, settings: &Settings) -> Self {
+        self.push(settings.apply("ascii_class", ascii_list("ascii_class", class, false)))
+    }
+
+    /// Adds a negated ASCII class, e.g. ```[[:^alpha:]]```, matching any character not in the class.
+    pub fn non_ascii_class(self, class: AsciiClass, settings: &Settings) -> Self {
+        self.push(settings.apply("non_ascii_class", ascii_list("non_ascii_class", class, true)))
     }
 
     /// Adds the ```\B``` metacharacter, asserts position anywhere but NOT at a word boundary.
     pub fn non_word_boundary(self) -> Self {
-        let result = format!("{}\\B", self.0);
-        EasyRegex(result)
+        self.push(vec![Node::Assertion(Assertion::NonWordBoundary)])
     }
 
     /// Adds the ending metacharacter ```\z```, asserts position at the end of the text.
     pub fn only_the_end(self) -> Self {
-        let result = format!("{}\\z", self.0);
-        EasyRegex(result)
+        self.push(vec![Node::Assertion(Assertion::OnlyTheEnd)])
+    }
+}
+
+fn metacharacter(method: &'static str, expression: &str, settings: &Settings) -> Vec<Node> {
+    let node = Node::Raw {
+        expression: expression.to_string(),
+        method,
+    };
+    settings.apply(method, node)
+}
+
+fn ascii_list(method: &'static str, class: AsciiClass, negated: bool) -> Node {
+    Node::Class {
+        negated: false,
+        items: vec![class.item(method, negated)],
+        method,
     }
 }
@@ -0,0 +1,211 @@
+//! Matches many patterns in a single pass.
+//!
+//! An ```EasyRegexSet``` collects named ```EasyRegex``` sections and compiles them into
+//! a [`RegexSet`](https://docs.rs/regex/latest/regex/struct.RegexSet.html), which reports the names of the patterns
+//! matching a haystack without running each of them separately.
+//! Since a set can't extract captures, the [`captures`](struct.NamedRegexSet.html#method.captures) method re-runs
+//! the individual regexes of the matching patterns only, compiling each of them the first time it is needed.
+
+use std::sync::OnceLock;
+
+use regex::{Captures, Regex, RegexSet};
+
+use crate::{EasyRegex, EasyRegexError};
+
+#[derive(Debug, Clone, Default)]
+/// Named sections to be compiled into a set of patterns.
+pub struct EasyRegexSet {
+    sections: Vec<(String, EasyRegex)>,
+}
+
+#[derive(Debug, Clone)]
+/// A compiled ```EasyRegexSet``` keeping the name and pattern of each section.
+pub struct NamedRegexSet {
+    set: RegexSet,
+    names: Vec<String>,
+    regexes: Vec<OnceLock<Regex>>,
+}
+
+impl EasyRegexSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        EasyRegexSet::default()
+    }
+
+    /// Adds a section to the set under the given name.
+    pub fn add(mut self, name: &str, section: EasyRegex) -> Self {
+        self.sections.push((name.to_string(), section));
+        self
+    }
+
+    /// Number of sections in the set.
+    pub fn len(&self) -> usize {
+        self.sections.len()
+    }
+
+    /// Checks if no section is added to the set.
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+
+    /// Compiles all sections into a single ```RegexSet```.
+    ///
+    /// Misuse of the chained methods of any section is reported before compiling,
+    /// as well as two sections sharing the same name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, set::EasyRegexSet, settings::base::*};
+    ///
+    /// let set = EasyRegexSet::new()
+    ///     .add("error", EasyRegex::start_of_line().literal("ERROR", &DEFAULT))
+    ///     .add("timeout", EasyRegex::new_section().literal("timed out", &DEFAULT))
+    ///     .add("warning", EasyRegex::start_of_line().literal("WARN", &DEFAULT))
+    ///     .get_regex_set()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(vec!["error", "timeout"], set.matches("ERROR: request timed out"));
+    /// assert!(!set.is_match("INFO: done"));
+    /// ```
+    pub fn get_regex_set(self) -> Result<NamedRegexSet, EasyRegexError> {
+        let mut names: Vec<String> = Vec::with_capacity(self.sections.len());
+        let mut patterns = Vec::with_capacity(self.sections.len());
+        for (name, section) in self.sections {
+            if names.contains(&name) {
+                return Err(EasyRegexError::DuplicatePatternName { name });
+            }
+            section.check::<RegexSet>(true)?;
+            names.push(name);
+            patterns.push(section.into_string());
+        }
+        let set = RegexSet::new(&patterns)?;
+        Ok(NamedRegexSet {
+            set,
+            regexes: vec![OnceLock::new(); names.len()],
+            names,
+        })
+    }
+}
+
+impl NamedRegexSet {
+    /// The underlying ```RegexSet```, whose pattern indices follow the order sections are added in.
+    pub fn regex_set(&self) -> &RegexSet {
+        &self.set
+    }
+
+    /// Names of the patterns in the order they are added.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Checks if any pattern matches the haystack.
+    pub fn is_match(&self, haystack: &str) -> bool {
+        self.set.is_match(haystack)
+    }
+
+    /// Names of the patterns matching the haystack.
+    pub fn matches(&self, haystack: &str) -> Vec<&str> {
+        self.set
+            .matches(haystack)
+            .into_iter()
+            .map(|index| self.names[index].as_str())
+            .collect()
+    }
+
+    /// Captures of the first match of each pattern matching the haystack, along with its name.
+    ///
+    /// Only the regexes of the matching patterns are run, and compiled if they aren't yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, set::EasyRegexSet, settings::{base::*, group::DEFAULT_GROUP}};
+    ///
+    /// let set = EasyRegexSet::new()
+    ///     .add("status", EasyRegex::new_section().literal("status=", &DEFAULT).group(r"\d{3}", &DEFAULT_GROUP))
+    ///     .add("user", EasyRegex::new_section().literal("user=", &DEFAULT).named_group("id", r"\w+", &DEFAULT_GROUP))
+    ///     .get_regex_set()
+    ///     .unwrap();
+    ///
+    /// let captures = set.captures("GET / status=404").unwrap();
+    /// assert_eq!(1, captures.len());
+    /// assert_eq!("status", captures[0].0);
+    /// assert_eq!("404", &captures[0].1[1]);
+    /// ```
+    pub fn captures<'h>(
+        &self,
+        haystack: &'h str,
+    ) -> Result<Vec<(&str, Captures<'h>)>, EasyRegexError> {
+        let mut found = Vec::new();
+        for index in self.set.matches(haystack).into_iter() {
+            let regex = match self.regexes[index].get() {
+                Some(regex) => regex,
+                None => {
+                    let regex = Regex::new(&self.set.patterns()[index])?;
+                    self.regexes[index].get_or_init(|| regex)
+                }
+            };
+            if let Some(captures) = regex.captures(haystack) {
+                found.push((self.names[index].as_str(), captures));
+            }
+        }
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{base::*, group::DEFAULT_GROUP, Settings};
+
+    #[test]
+    fn regex_set_works() {
+        let set = EasyRegexSet::new()
+            .add("digits", EasyRegex::new_section().digit(&ONE_OR_MORE))
+            .add(
+                "key",
+                EasyRegex::new_section()
+                    .named_group("key", r"[a-z]+", &DEFAULT_GROUP)
+                    .literal("=", &DEFAULT),
+            )
+            .add("dash", EasyRegex::new_section().literal("-", &DEFAULT));
+        assert_eq!(3, set.len());
+        let set = set.get_regex_set().unwrap();
+        assert_eq!(vec!["digits", "key"], set.matches("retries=3"));
+        assert_eq!(3, set.regex_set().len());
+
+        let captures = set.captures("retries=3").unwrap();
+        assert_eq!(2, captures.len());
+        assert_eq!("3", &captures[0].1[0]);
+        assert_eq!("retries", &captures[1].1["key"]);
+        assert!(set.regexes[0].get().is_some());
+        assert!(set.regexes[2].get().is_none());
+    }
+
+    #[test]
+    fn regex_set_errors() {
+        let duplicate = EasyRegexSet::new()
+            .add("a", EasyRegex::new("a"))
+            .add("a", EasyRegex::new("b"))
+            .get_regex_set();
+        assert_eq!(
+            EasyRegexError::DuplicatePatternName {
+                name: "a".to_string()
+            },
+            duplicate.unwrap_err()
+        );
+
+        let misuse = EasyRegexSet::new()
+            .add("a", EasyRegex::new("a"))
+            .add(
+                "b",
+                EasyRegex::new_section().digit(&Settings::range(None, None)),
+            )
+            .get_regex_set();
+        assert_eq!(
+            EasyRegexError::EmptyRange { method: "digit" },
+            misuse.unwrap_err()
+        );
+    }
+}
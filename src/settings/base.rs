@@ -2,7 +2,7 @@
 //! 
 //! These are adjusted **base settings** to be used in methods like [`literal`](../../struct.EasyRegex.html#method.literal) to save time and make the code more readable.
 
-use crate::settings::{Settings, Flags};
+use crate::settings::{Settings, Flags, Quantifier};
 
 lazy_static! {
     pub static ref DEFAULT: Settings = Settings {
@@ -16,6 +16,7 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
+        quantifier: None,
         flags: None
     };
     pub static ref OPTIONAL: Settings = Settings {
@@ -29,6 +30,7 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
+        quantifier: None,
         flags: None
     };
     pub static ref OPTIONAL_UNGREEDY: Settings = Settings {
@@ -42,6 +44,7 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
+        quantifier: None,
         flags: None
     };
     pub static ref ONE_OR_MORE: Settings = Settings {
@@ -55,11 +58,12 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
+        quantifier: None,
         flags: None
     };
     pub static ref ONE_OR_MORE_UNGREEDY: Settings = Settings {
-        is_optional: true,
-        is_one_or_more: true,
+        is_optional: false,
+        is_one_or_more: false,
         is_nil_or_more: false,
         is_optional_ungreedy: false,
         with_left_boundary: false,
@@ -68,6 +72,7 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
+        quantifier: Some(Quantifier::OneOrMoreUngreedy),
         flags: None
     };
     pub static ref NIL_OR_MORE: Settings = Settings {
@@ -81,12 +86,13 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
+        quantifier: None,
         flags: None
     };
     pub static ref NIL_OR_MORE_UNGREEDY: Settings = Settings {
-        is_optional: true,
+        is_optional: false,
         is_one_or_more: false,
-        is_nil_or_more: true,
+        is_nil_or_more: false,
         is_optional_ungreedy: false,
         with_left_boundary: false,
         with_left_non_boundary: false,
@@ -94,6 +100,7 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
+        quantifier: Some(Quantifier::NilOrMoreUngreedy),
         flags: None
     };
     pub static ref LEFT_BOUNDARY: Settings = Settings {
@@ -107,6 +114,7 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
+        quantifier: None,
         flags: None
     };
     pub static ref RIGHT_BOUNDARY: Settings = Settings {
@@ -120,6 +128,7 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
+        quantifier: None,
         flags: None
     };
     pub static ref BOTH_BOUNDARY: Settings = Settings {
@@ -133,6 +142,7 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
+        quantifier: None,
         flags: None
     };
     pub static ref LEFT_NON_BOUNDARY: Settings = Settings {
@@ -146,6 +156,7 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
+        quantifier: None,
         flags: None
     };
     pub static ref RIGHT_NON_BOUNDARY: Settings = Settings {
@@ -159,6 +170,7 @@ lazy_static! {
         with_right_non_boundary: true,
         range: None,
         exactly: None,
+        quantifier: None,
         flags: None
     };
     pub static ref BOTH_NON_BOUNDARY: Settings = Settings {
@@ -172,6 +184,7 @@ lazy_static! {
         with_right_non_boundary: true,
         range: None,
         exactly: None,
+        quantifier: None,
         flags: None
     };
     pub static ref INSENSITIVE: Settings = Settings {
@@ -185,7 +198,8 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
-        flags: Some(Flags::Insensitive),
+        quantifier: None,
+        flags: Some(Flags::Insensitive.into()),
     };
     pub static ref MULTILINE: Settings = Settings {
         is_optional: false,
@@ -198,7 +212,8 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
-        flags: Some(Flags::Multiline),
+        quantifier: None,
+        flags: Some(Flags::Multiline.into()),
     };
     pub static ref DOT_MATCH_NEWLINE: Settings = Settings {
         is_optional: false,
@@ -211,7 +226,8 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
-        flags: Some(Flags::DotMatchNewLine),
+        quantifier: None,
+        flags: Some(Flags::DotMatchNewLine.into()),
     };
     pub static ref IGNORE_WHITESPACE: Settings = Settings {
         is_optional: false,
@@ -224,7 +240,8 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
-        flags: Some(Flags::IgnoreWhitespace),
+        quantifier: None,
+        flags: Some(Flags::IgnoreWhitespace.into()),
     };
     pub static ref SENSITIVE: Settings = Settings {
         is_optional: false,
@@ -237,7 +254,8 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
-        flags: Some(Flags::Sensitive),
+        quantifier: None,
+        flags: Some(Flags::Sensitive.into()),
     };
     pub static ref SINGLE_LINE: Settings = Settings {
         is_optional: false,
@@ -250,7 +268,8 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
-        flags: Some(Flags::SingleLine),
+        quantifier: None,
+        flags: Some(Flags::SingleLine.into()),
     };
     pub static ref DOT_DISMATCH_NEWLINE: Settings = Settings {
         is_optional: false,
@@ -263,7 +282,8 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
-        flags: Some(Flags::DotDisMatchNewLine),
+        quantifier: None,
+        flags: Some(Flags::DotDisMatchNewLine.into()),
     };
     pub static ref INCLUDE_WHITESPACE: Settings = Settings {
         is_optional: false,
@@ -276,6 +296,91 @@ lazy_static! {
         with_right_non_boundary: false,
         range: None,
         exactly: None,
-        flags: Some(Flags::IncludeWhitespace),
+        quantifier: None,
+        flags: Some(Flags::IncludeWhitespace.into()),
+    };
+    pub static ref SWAP_GREED: Settings = Settings {
+        is_optional: false,
+        is_one_or_more: false,
+        is_nil_or_more: false,
+        is_optional_ungreedy: false,
+        with_left_boundary: false,
+        with_left_non_boundary: false,
+        with_right_boundary: false,
+        with_right_non_boundary: false,
+        range: None,
+        exactly: None,
+        quantifier: None,
+        flags: Some(Flags::SwapGreed.into()),
+    };
+    pub static ref CRLF: Settings = Settings {
+        is_optional: false,
+        is_one_or_more: false,
+        is_nil_or_more: false,
+        is_optional_ungreedy: false,
+        with_left_boundary: false,
+        with_left_non_boundary: false,
+        with_right_boundary: false,
+        with_right_non_boundary: false,
+        range: None,
+        exactly: None,
+        quantifier: None,
+        flags: Some(Flags::Crlf.into()),
+    };
+    pub static ref UNICODE: Settings = Settings {
+        is_optional: false,
+        is_one_or_more: false,
+        is_nil_or_more: false,
+        is_optional_ungreedy: false,
+        with_left_boundary: false,
+        with_left_non_boundary: false,
+        with_right_boundary: false,
+        with_right_non_boundary: false,
+        range: None,
+        exactly: None,
+        quantifier: None,
+        flags: Some(Flags::Unicode.into()),
+    };
+    pub static ref NO_SWAP_GREED: Settings = Settings {
+        is_optional: false,
+        is_one_or_more: false,
+        is_nil_or_more: false,
+        is_optional_ungreedy: false,
+        with_left_boundary: false,
+        with_left_non_boundary: false,
+        with_right_boundary: false,
+        with_right_non_boundary: false,
+        range: None,
+        exactly: None,
+        quantifier: None,
+        flags: Some(Flags::NoSwapGreed.into()),
+    };
+    pub static ref NO_CRLF: Settings = Settings {
+        is_optional: false,
+        is_one_or_more: false,
+        is_nil_or_more: false,
+        is_optional_ungreedy: false,
+        with_left_boundary: false,
+        with_left_non_boundary: false,
+        with_right_boundary: false,
+        with_right_non_boundary: false,
+        range: None,
+        exactly: None,
+        quantifier: None,
+        flags: Some(Flags::NoCrlf.into()),
+    };
+    pub static ref NO_UNICODE: Settings = Settings {
+        is_optional: false,
+        is_one_or_more: false,
+        is_nil_or_more: false,
+        is_optional_ungreedy: false,
+        with_left_boundary: false,
+        with_left_non_boundary: false,
+        with_right_boundary: false,
+        with_right_non_boundary: false,
+        range: None,
+        exactly: None,
+        quantifier: None,
+        flags: Some(Flags::NoUnicode.into()),
     };
 }
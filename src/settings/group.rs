@@ -2,7 +2,7 @@
 //! 
 //! These are adjusted **group settings** to be used in methods like [`group`](../../struct.EasyRegex.html#method.group) to save time and make the code more readable.
 
-use crate::settings::{Flags, GroupSettings, Settings};
+use crate::settings::{Flags, GroupSettings, Quantifier, Settings};
 
 lazy_static! {
     pub static ref DEFAULT_GROUP: GroupSettings = GroupSettings {
@@ -18,6 +18,7 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
+            quantifier: None,
             flags: None
         },
     };
@@ -34,6 +35,7 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
+            quantifier: None,
             flags: None
         },
     };
@@ -50,6 +52,75 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
+            quantifier: None,
+            flags: None
+        },
+    };
+    pub static ref ONE_OR_MORE_GROUP: GroupSettings = GroupSettings {
+        is_non_capture: false,
+        other: Settings {
+            is_optional: false,
+            is_optional_ungreedy: false,
+            is_one_or_more: true,
+            is_nil_or_more: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: None,
+            flags: None
+        },
+    };
+    pub static ref ONE_OR_MORE_GROUP_UNGREEDY: GroupSettings = GroupSettings {
+        is_non_capture: false,
+        other: Settings {
+            is_optional: false,
+            is_optional_ungreedy: false,
+            is_one_or_more: false,
+            is_nil_or_more: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: Some(Quantifier::OneOrMoreUngreedy),
+            flags: None
+        },
+    };
+    pub static ref NIL_OR_MORE_GROUP: GroupSettings = GroupSettings {
+        is_non_capture: false,
+        other: Settings {
+            is_optional: false,
+            is_optional_ungreedy: false,
+            is_one_or_more: false,
+            is_nil_or_more: true,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: None,
+            flags: None
+        },
+    };
+    pub static ref NIL_OR_MORE_GROUP_UNGREEDY: GroupSettings = GroupSettings {
+        is_non_capture: false,
+        other: Settings {
+            is_optional: false,
+            is_optional_ungreedy: false,
+            is_one_or_more: false,
+            is_nil_or_more: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: Some(Quantifier::NilOrMoreUngreedy),
             flags: None
         },
     };
@@ -66,6 +137,7 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
+            quantifier: None,
             flags: None
         },
     };
@@ -82,7 +154,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::Insensitive),
+            quantifier: None,
+            flags: Some(Flags::Insensitive.into()),
         },
     };
     pub static ref MULTILINE_GROUP: GroupSettings = GroupSettings {
@@ -98,7 +171,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::Multiline),
+            quantifier: None,
+            flags: Some(Flags::Multiline.into()),
         },
     };
     pub static ref DOT_MATCH_NEWLINE_GROUP: GroupSettings = GroupSettings {
@@ -114,7 +188,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::DotMatchNewLine),
+            quantifier: None,
+            flags: Some(Flags::DotMatchNewLine.into()),
         },
     };
     pub static ref IGNORE_WHITESPACE_GROUP: GroupSettings = GroupSettings {
@@ -130,7 +205,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::IgnoreWhitespace),
+            quantifier: None,
+            flags: Some(Flags::IgnoreWhitespace.into()),
         },
     };
     pub static ref INSENSITIVE_NON_CAPTURE: GroupSettings = GroupSettings {
@@ -146,7 +222,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::Insensitive),
+            quantifier: None,
+            flags: Some(Flags::Insensitive.into()),
         },
     };
     pub static ref MULTILINE_NON_CAPTURE: GroupSettings = GroupSettings {
@@ -162,7 +239,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::Multiline),
+            quantifier: None,
+            flags: Some(Flags::Multiline.into()),
         },
     };
     pub static ref DOT_MATCH_NEWLINE_NON_CAPTURE: GroupSettings = GroupSettings {
@@ -178,7 +256,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::DotMatchNewLine),
+            quantifier: None,
+            flags: Some(Flags::DotMatchNewLine.into()),
         },
     };
     pub static ref IGNORE_WHITESPACE_NON_CAPTURE: GroupSettings = GroupSettings {
@@ -194,7 +273,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::IgnoreWhitespace),
+            quantifier: None,
+            flags: Some(Flags::IgnoreWhitespace.into()),
         },
     };
     pub static ref SENSITIVE_GROUP: GroupSettings = GroupSettings {
@@ -210,7 +290,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::Sensitive),
+            quantifier: None,
+            flags: Some(Flags::Sensitive.into()),
         },
     };
     pub static ref SINGLE_LINE_GROUP: GroupSettings = GroupSettings {
@@ -226,7 +307,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::SingleLine),
+            quantifier: None,
+            flags: Some(Flags::SingleLine.into()),
         },
     };
     pub static ref DOT_DISMATCH_NEWLINE_GROUP: GroupSettings = GroupSettings {
@@ -242,7 +324,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::DotDisMatchNewLine),
+            quantifier: None,
+            flags: Some(Flags::DotDisMatchNewLine.into()),
         },
     };
     pub static ref INCLUDE_WHITESPACE_GROUP: GroupSettings = GroupSettings {
@@ -258,7 +341,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::IncludeWhitespace),
+            quantifier: None,
+            flags: Some(Flags::IncludeWhitespace.into()),
         },
     };
     pub static ref SENSITIVE_NON_CAPTURE: GroupSettings = GroupSettings {
@@ -274,7 +358,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::Sensitive),
+            quantifier: None,
+            flags: Some(Flags::Sensitive.into()),
         },
     };
     pub static ref SINGLE_LINE_NON_CAPTURE: GroupSettings = GroupSettings {
@@ -290,7 +375,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::SingleLine),
+            quantifier: None,
+            flags: Some(Flags::SingleLine.into()),
         },
     };
     pub static ref DOT_DISMATCH_NEWLINE_NON_CAPTURE: GroupSettings = GroupSettings {
@@ -306,7 +392,8 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::DotDisMatchNewLine),
+            quantifier: None,
+            flags: Some(Flags::DotDisMatchNewLine.into()),
         },
     };
     pub static ref INCLUDE_WHITESPACE_NON_CAPTURE: GroupSettings = GroupSettings {
@@ -322,7 +409,212 @@ lazy_static! {
             with_right_non_boundary: false,
             range: None,
             exactly: None,
-            flags: Some(Flags::IncludeWhitespace),
+            quantifier: None,
+            flags: Some(Flags::IncludeWhitespace.into()),
+        },
+    };
+    pub static ref SWAP_GREED_GROUP: GroupSettings = GroupSettings {
+        is_non_capture: false,
+        other: Settings {
+            is_optional: false,
+            is_one_or_more: false,
+            is_nil_or_more: false,
+            is_optional_ungreedy: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: None,
+            flags: Some(Flags::SwapGreed.into()),
+        },
+    };
+    pub static ref CRLF_GROUP: GroupSettings = GroupSettings {
+        is_non_capture: false,
+        other: Settings {
+            is_optional: false,
+            is_one_or_more: false,
+            is_nil_or_more: false,
+            is_optional_ungreedy: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: None,
+            flags: Some(Flags::Crlf.into()),
+        },
+    };
+    pub static ref UNICODE_GROUP: GroupSettings = GroupSettings {
+        is_non_capture: false,
+        other: Settings {
+            is_optional: false,
+            is_one_or_more: false,
+            is_nil_or_more: false,
+            is_optional_ungreedy: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: None,
+            flags: Some(Flags::Unicode.into()),
+        },
+    };
+    pub static ref SWAP_GREED_NON_CAPTURE: GroupSettings = GroupSettings {
+        is_non_capture: true,
+        other: Settings {
+            is_optional: false,
+            is_optional_ungreedy: false,
+            is_one_or_more: false,
+            is_nil_or_more: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: None,
+            flags: Some(Flags::SwapGreed.into()),
+        },
+    };
+    pub static ref CRLF_NON_CAPTURE: GroupSettings = GroupSettings {
+        is_non_capture: true,
+        other: Settings {
+            is_optional: false,
+            is_optional_ungreedy: false,
+            is_one_or_more: false,
+            is_nil_or_more: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: None,
+            flags: Some(Flags::Crlf.into()),
+        },
+    };
+    pub static ref UNICODE_NON_CAPTURE: GroupSettings = GroupSettings {
+        is_non_capture: true,
+        other: Settings {
+            is_optional: false,
+            is_optional_ungreedy: false,
+            is_one_or_more: false,
+            is_nil_or_more: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: None,
+            flags: Some(Flags::Unicode.into()),
+        },
+    };
+    pub static ref NO_SWAP_GREED_GROUP: GroupSettings = GroupSettings {
+        is_non_capture: false,
+        other: Settings {
+            is_optional: false,
+            is_one_or_more: false,
+            is_nil_or_more: false,
+            is_optional_ungreedy: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: None,
+            flags: Some(Flags::NoSwapGreed.into()),
+        },
+    };
+    pub static ref NO_CRLF_GROUP: GroupSettings = GroupSettings {
+        is_non_capture: false,
+        other: Settings {
+            is_optional: false,
+            is_one_or_more: false,
+            is_nil_or_more: false,
+            is_optional_ungreedy: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: None,
+            flags: Some(Flags::NoCrlf.into()),
+        },
+    };
+    pub static ref NO_UNICODE_GROUP: GroupSettings = GroupSettings {
+        is_non_capture: false,
+        other: Settings {
+            is_optional: false,
+            is_one_or_more: false,
+            is_nil_or_more: false,
+            is_optional_ungreedy: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: None,
+            flags: Some(Flags::NoUnicode.into()),
+        },
+    };
+    pub static ref NO_SWAP_GREED_NON_CAPTURE: GroupSettings = GroupSettings {
+        is_non_capture: true,
+        other: Settings {
+            is_optional: false,
+            is_optional_ungreedy: false,
+            is_one_or_more: false,
+            is_nil_or_more: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: None,
+            flags: Some(Flags::NoSwapGreed.into()),
+        },
+    };
+    pub static ref NO_CRLF_NON_CAPTURE: GroupSettings = GroupSettings {
+        is_non_capture: true,
+        other: Settings {
+            is_optional: false,
+            is_optional_ungreedy: false,
+            is_one_or_more: false,
+            is_nil_or_more: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: None,
+            flags: Some(Flags::NoCrlf.into()),
+        },
+    };
+    pub static ref NO_UNICODE_NON_CAPTURE: GroupSettings = GroupSettings {
+        is_non_capture: true,
+        other: Settings {
+            is_optional: false,
+            is_optional_ungreedy: false,
+            is_one_or_more: false,
+            is_nil_or_more: false,
+            with_left_boundary: false,
+            with_left_non_boundary: false,
+            with_right_boundary: false,
+            with_right_non_boundary: false,
+            range: None,
+            exactly: None,
+            quantifier: None,
+            flags: Some(Flags::NoUnicode.into()),
         },
     };
 }
@@ -7,12 +7,31 @@
 //! There is a default implemention for both structs for ease of use and to reduce typing repetitive meta over and over as well as shortening code length.
 //! Two methods are provided for setting an exact or a range of repetitions for an expression in case the only meta is either of them.
 //! Those are ```exactly``` and ```range``` for non-group expressions called ```base``` settings and ```grp_exactly``` and ```grp_range``` for ```group``` settings.
+//! Repetition counts are ```u32``` values, the same as the counts accepted by the regex crate's parser.
+//! Since the regex crate expands every repetition when compiling, large counts may go beyond its size limit,
+//! reported as ```EasyRegexError::Regex``` unless a higher ```size_limit``` is given by ```CompileOptions```.
+//! Quantifiers could be set either by the boolean fields of ```Settings``` or as a single [`Quantifier`](enum.Quantifier.html)
+//! which can't be combined into invalid outputs like ```*+```.
+
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+    ops::BitOr,
+};
+
+use crate::{
+    ast::{Assertion, GroupKind, Node},
+    error::EasyRegexError,
+};
 
 pub mod base;
 pub mod group;
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// Regular Expression Flags
+///
+/// Flags could be combined into a [`FlagSet`](struct.FlagSet.html) by the ```|``` operator,
+/// e.g. ```Flags::Insensitive | Flags::SingleLine``` makes ```?i-m```.
 pub enum Flags {
     Insensitive,
     Multiline,
@@ -22,6 +41,12 @@ pub enum Flags {
     SingleLine,
     DotDisMatchNewLine,
     IncludeWhitespace,
+    SwapGreed,
+    NoSwapGreed,
+    Crlf,
+    NoCrlf,
+    Unicode,
+    NoUnicode,
 }
 
 impl Flags {
@@ -43,11 +68,351 @@ impl Flags {
             Flags::SingleLine => "?-m",
             Flags::DotDisMatchNewLine => "?-s",
             Flags::IncludeWhitespace => "?-x",
+            Flags::SwapGreed => "?U",
+            Flags::NoSwapGreed => "?-U",
+            Flags::Crlf => "?R",
+            Flags::NoCrlf => "?-R",
+            Flags::Unicode => "?u",
+            Flags::NoUnicode => "?-u",
+        }
+    }
+
+    /// The letter of the flag and whether it is set or cleared.
+    const fn letter(&self) -> (u8, bool) {
+        match self {
+            Flags::Insensitive => (FlagSet::I, true),
+            Flags::Multiline => (FlagSet::M, true),
+            Flags::DotMatchNewLine => (FlagSet::S, true),
+            Flags::IgnoreWhitespace => (FlagSet::X, true),
+            Flags::Sensitive => (FlagSet::I, false),
+            Flags::SingleLine => (FlagSet::M, false),
+            Flags::DotDisMatchNewLine => (FlagSet::S, false),
+            Flags::IncludeWhitespace => (FlagSet::X, false),
+            Flags::SwapGreed => (FlagSet::SWAP_GREED, true),
+            Flags::NoSwapGreed => (FlagSet::SWAP_GREED, false),
+            Flags::Crlf => (FlagSet::CRLF, true),
+            Flags::NoCrlf => (FlagSet::CRLF, false),
+            Flags::Unicode => (FlagSet::UNICODE, true),
+            Flags::NoUnicode => (FlagSet::UNICODE, false),
+        }
+    }
+}
+
+impl BitOr for Flags {
+    type Output = FlagSet;
+
+    fn bitor(self, flag: Flags) -> Self::Output {
+        FlagSet::new().with(self).with(flag)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// A set of flags, each of them either set or cleared, rendered compactly as e.g. ```?is-m```.
+///
+/// Besides the ```Flags``` enum, each flag of the regex crate could be set or cleared by the method
+/// having the same name as its ```RegexBuilder``` option, e.g. ```swap_greed``` for ```U```.
+/// It's used by the ```flags``` field of ```Settings``` and ```GroupSettings```, and by the
+/// [`flags`](../struct.EasyRegex.html#method.flags) and [`with_flags`](../struct.EasyRegex.html#method.with_flags) methods.
+///
+/// # Examples
+///
+/// ```
+/// use easy_regex::settings::{FlagSet, Flags};
+///
+/// let flags = FlagSet::new().with(Flags::Insensitive).with(Flags::SingleLine) | Flags::DotMatchNewLine;
+/// assert_eq!("?is-m", flags.to_string());
+///
+/// let flags = FlagSet::new().crlf(true).multi_line(true).unicode(false).swap_greed(true);
+/// assert_eq!("?mUR-u", flags.to_string());
+/// ```
+pub struct FlagSet {
+    set: u8,
+    cleared: u8,
+}
+
+impl FlagSet {
+    const I: u8 = 1;
+    const M: u8 = 1 << 1;
+    const S: u8 = 1 << 2;
+    const X: u8 = 1 << 3;
+    const SWAP_GREED: u8 = 1 << 4;
+    const CRLF: u8 = 1 << 5;
+    const UNICODE: u8 = 1 << 6;
+    const LETTERS: [(u8, char); 7] = [
+        (FlagSet::I, 'i'),
+        (FlagSet::M, 'm'),
+        (FlagSet::S, 's'),
+        (FlagSet::X, 'x'),
+        (FlagSet::SWAP_GREED, 'U'),
+        (FlagSet::CRLF, 'R'),
+        (FlagSet::UNICODE, 'u'),
+    ];
+
+    /// Creates an empty set.
+    pub const fn new() -> Self {
+        FlagSet { set: 0, cleared: 0 }
+    }
+
+    /// Adds a flag to the set, replacing the opposite one if it's already there.
+    pub const fn with(self, flag: Flags) -> Self {
+        let (letter, is_set) = flag.letter();
+        self.toggle(letter, is_set)
+    }
+
+    /// Sets ```i``` or clears it by ```-i```.
+    pub const fn case_insensitive(self, yes: bool) -> Self {
+        self.toggle(FlagSet::I, yes)
+    }
+
+    /// Sets ```m``` or clears it by ```-m```.
+    pub const fn multi_line(self, yes: bool) -> Self {
+        self.toggle(FlagSet::M, yes)
+    }
+
+    /// Sets ```s``` or clears it by ```-s```.
+    pub const fn dot_matches_new_line(self, yes: bool) -> Self {
+        self.toggle(FlagSet::S, yes)
+    }
+
+    /// Sets ```x``` or clears it by ```-x```.
+    pub const fn ignore_whitespace(self, yes: bool) -> Self {
+        self.toggle(FlagSet::X, yes)
+    }
+
+    /// Sets ```U```, swapping the meaning of greedy and ungreedy quantifiers, or clears it by ```-U```.
+    pub const fn swap_greed(self, yes: bool) -> Self {
+        self.toggle(FlagSet::SWAP_GREED, yes)
+    }
+
+    /// Sets ```R```, making ```^``` and ```$``` of multi-line mode aware of ```\r\n```, or clears it by ```-R```.
+    pub const fn crlf(self, yes: bool) -> Self {
+        self.toggle(FlagSet::CRLF, yes)
+    }
+
+    /// Sets ```u```, making classes and case folding Unicode-aware, or clears it by ```-u```.
+    pub const fn unicode(self, yes: bool) -> Self {
+        self.toggle(FlagSet::UNICODE, yes)
+    }
+
+    const fn toggle(self, letter: u8, yes: bool) -> Self {
+        if yes {
+            FlagSet {
+                set: self.set | letter,
+                cleared: self.cleared & !letter,
+            }
+        } else {
+            FlagSet {
+                set: self.set & !letter,
+                cleared: self.cleared | letter,
+            }
         }
     }
+
+    /// Checks if the given flag is in the set.
+    pub fn contains(&self, flag: Flags) -> bool {
+        let (letter, is_set) = flag.letter();
+        let side = if is_set { self.set } else { self.cleared };
+        side & letter != 0
+    }
+
+    /// Checks if no flag is in the set.
+    pub fn is_empty(&self) -> bool {
+        self.set == 0 && self.cleared == 0
+    }
+}
+
+impl From<FlagSet> for Settings {
+    fn from(flags: FlagSet) -> Self {
+        Settings {
+            flags: Some(flags),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<FlagSet> for GroupSettings {
+    fn from(flags: FlagSet) -> Self {
+        GroupSettings {
+            other: flags.into(),
+            is_non_capture: false,
+        }
+    }
+}
+
+impl From<Flags> for FlagSet {
+    fn from(flag: Flags) -> Self {
+        FlagSet::new().with(flag)
+    }
+}
+
+impl BitOr<Flags> for FlagSet {
+    type Output = FlagSet;
+
+    fn bitor(self, flag: Flags) -> Self::Output {
+        self.with(flag)
+    }
+}
+
+impl BitOr for FlagSet {
+    type Output = FlagSet;
+
+    fn bitor(self, other: FlagSet) -> Self::Output {
+        FlagSet {
+            set: (self.set & !other.cleared) | other.set,
+            cleared: (self.cleared & !other.set) | other.cleared,
+        }
+    }
+}
+
+impl Display for FlagSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let letters = |side: u8| -> String {
+            FlagSet::LETTERS
+                .iter()
+                .filter(|(letter, _)| side & letter != 0)
+                .map(|(_, name)| name)
+                .collect()
+        };
+        write!(f, "?{}", letters(self.set))?;
+        if self.cleared != 0 {
+            write!(f, "-{}", letters(self.cleared))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Regular Expression Quantifiers, each having a greedy and an ungreedy (lazy) variant.
+pub enum Quantifier {
+    /// ```?```
+    Optional,
+    /// ```??```
+    OptionalUngreedy,
+    /// ```*```
+    NilOrMore,
+    /// ```*?```
+    NilOrMoreUngreedy,
+    /// ```+```
+    OneOrMore,
+    /// ```+?```
+    OneOrMoreUngreedy,
+    /// ```{n}```
+    Exactly(u32),
+    /// ```{n}?```
+    ExactlyUngreedy(u32),
+    /// ```{n,}```
+    AtLeast(u32),
+    /// ```{n,}?```
+    AtLeastUngreedy(u32),
+    /// ```{n,m}```
+    Range(u32, u32),
+    /// ```{n,m}?```
+    RangeUngreedy(u32, u32),
+    /// ```{0,m}```
+    AtMost(u32),
+    /// ```{0,m}?```
+    AtMostUngreedy(u32),
+}
+
+impl Quantifier {
+    /// Checks if the quantifier matches as many repetitions as possible.
+    pub fn is_greedy(&self) -> bool {
+        self.greedy() == *self
+    }
+
+    /// Converts a quantifier into its ungreedy (lazy) variant.
+    ///
+    /// # Examples
+    /// ```
+    /// use easy_regex::settings::Quantifier;
+    ///
+    /// assert_eq!(Quantifier::RangeUngreedy(2, 5), Quantifier::Range(2, 5).ungreedy());
+    /// ```
+    pub fn ungreedy(self) -> Self {
+        match self {
+            Quantifier::Optional => Quantifier::OptionalUngreedy,
+            Quantifier::NilOrMore => Quantifier::NilOrMoreUngreedy,
+            Quantifier::OneOrMore => Quantifier::OneOrMoreUngreedy,
+            Quantifier::Exactly(number) => Quantifier::ExactlyUngreedy(number),
+            Quantifier::AtLeast(from) => Quantifier::AtLeastUngreedy(from),
+            Quantifier::Range(from, to) => Quantifier::RangeUngreedy(from, to),
+            Quantifier::AtMost(to) => Quantifier::AtMostUngreedy(to),
+            ungreedy => ungreedy,
+        }
+    }
+
+    /// Converts a quantifier into its greedy variant.
+    pub fn greedy(self) -> Self {
+        match self {
+            Quantifier::OptionalUngreedy => Quantifier::Optional,
+            Quantifier::NilOrMoreUngreedy => Quantifier::NilOrMore,
+            Quantifier::OneOrMoreUngreedy => Quantifier::OneOrMore,
+            Quantifier::ExactlyUngreedy(number) => Quantifier::Exactly(number),
+            Quantifier::AtLeastUngreedy(from) => Quantifier::AtLeast(from),
+            Quantifier::RangeUngreedy(from, to) => Quantifier::Range(from, to),
+            Quantifier::AtMostUngreedy(to) => Quantifier::AtMost(to),
+            greedy => greedy,
+        }
+    }
+
+    /// Creates a ranged quantifier the same way as the ```range``` field of ```Settings```, ```None``` if both ends are missing.
+    fn from_range(from: Option<u32>, to: Option<u32>) -> Option<Self> {
+        match (from, to) {
+            (Some(from), Some(to)) => Some(Quantifier::Range(from, to)),
+            (Some(from), None) => Some(Quantifier::AtLeast(from)),
+            (None, Some(to)) => Some(Quantifier::AtMost(to)),
+            (None, None) => None,
+        }
+    }
+}
+
+impl Display for Quantifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.greedy() {
+            Quantifier::Optional => f.write_str("?")?,
+            Quantifier::NilOrMore => f.write_str("*")?,
+            Quantifier::OneOrMore => f.write_str("+")?,
+            Quantifier::Exactly(number) => write!(f, "{{{}}}", number)?,
+            Quantifier::AtLeast(from) => write!(f, "{{{},}}", from)?,
+            Quantifier::Range(from, to) => write!(f, "{{{},{}}}", from, to)?,
+            Quantifier::AtMost(to) => write!(f, "{{0,{}}}", to)?,
+            ungreedy => unreachable!("{:?} is not greedy", ungreedy),
+        }
+        if !self.is_greedy() {
+            f.write_str("?")?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&Settings> for Quantifier {
+    type Error = EasyRegexError;
+
+    /// Converts the quantifier fields of ```Settings``` into a single ```Quantifier```.
+    ///
+    /// Just like the output of ```Settings```, ```is_optional``` combined with another quantifier
+    /// makes that quantifier ungreedy. Settings having no quantifier or conflicting ones can't be converted.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use easy_regex::settings::{base::ONE_OR_MORE_UNGREEDY, Quantifier};
+    ///
+    /// let result = Quantifier::try_from(&*ONE_OR_MORE_UNGREEDY);
+    /// assert_eq!(Ok(Quantifier::OneOrMoreUngreedy), result);
+    /// ```
+    fn try_from(settings: &Settings) -> Result<Self, Self::Error> {
+        settings.validate("Quantifier::try_from")?;
+        settings
+            .quantifiers()
+            .first()
+            .copied()
+            .ok_or(EasyRegexError::MissingQuantifier)
+    }
 }
 
 /// A set of meta indicating what special characters (quantifiers), boundaries and flags should be added to an expression.
+#[derive(Default)]
 pub struct Settings {
     pub is_optional: bool,
     pub is_optional_ungreedy: bool,
@@ -57,62 +422,172 @@ pub struct Settings {
     pub with_left_non_boundary: bool,
     pub with_right_boundary: bool,
     pub with_right_non_boundary: bool,
-    pub range: Option<(Option<u8>, Option<u8>)>,
-    pub exactly: Option<u8>,
-    pub flags: Option<Flags>,
+    pub range: Option<(Option<u32>, Option<u32>)>,
+    pub exactly: Option<u32>,
+    pub quantifier: Option<Quantifier>,
+    pub flags: Option<FlagSet>,
 }
 
-impl Default for Settings {
-    fn default() -> Self {
+impl Settings {
+    pub fn exactly(number: u32) -> Self {
         Settings {
-            is_optional: false,
-            is_optional_ungreedy: false,
-            is_one_or_more: false,
-            is_nil_or_more: false,
-            with_left_boundary: false,
-            with_left_non_boundary: false,
-            with_right_boundary: false,
-            with_right_non_boundary: false,
-            range: None,
-            exactly: None,
-            flags: None,
+            exactly: Some(number),
+            ..Default::default()
         }
     }
-}
 
-impl Settings {
-    pub fn exactly(number: u8) -> Self {
+    pub fn range(from: Option<u32>, to: Option<u32>) -> Self {
         Settings {
-            exactly: Some(number),
+            range: Some((from, to)),
             ..Default::default()
         }
     }
 
-    pub fn range(from: Option<u8>, to: Option<u8>) -> Self {
+    /// Ungreedy variant of ```exactly``` i.e. ```{n}?```.
+    pub fn exactly_ungreedy(number: u32) -> Self {
         Settings {
-            range: Some((from, to)),
+            quantifier: Some(Quantifier::ExactlyUngreedy(number)),
+            ..Default::default()
+        }
+    }
+
+    /// Ungreedy variant of ```range``` e.g. ```{n,m}?```.
+    pub fn range_ungreedy(from: Option<u32>, to: Option<u32>) -> Self {
+        Settings {
+            quantifier: Quantifier::from_range(from, to).map(Quantifier::ungreedy),
+            ..Default::default()
+        }
+    }
+
+    /// Surrounds a node with the boundaries, flags and quantifiers of these settings.
+    ///
+    /// Invalid settings are recorded in the output under the name of the ```method``` they are passed to.
+    pub(crate) fn apply(&self, method: &'static str, node: Node) -> Vec<Node> {
+        let mut nodes = Vec::new();
+
+        if self.with_left_non_boundary {
+            nodes.push(Node::Assertion(Assertion::NonWordBoundary));
+        }
+        if self.with_left_boundary {
+            nodes.push(Node::Assertion(Assertion::WordBoundary));
+        }
+        if let Some(flags) = self.flags.filter(|flags| !flags.is_empty()) {
+            nodes.push(Node::Flags(flags));
+        }
+
+        let quantifiers = self.quantifiers();
+        let mut node = node;
+        if !quantifiers.is_empty() && matches!(node, Node::Choice(_)) {
+            node = Node::Group {
+                kind: GroupKind::NonCapturing,
+                nodes: vec![node],
+            };
+        }
+        for quantifier in quantifiers {
+            node = Node::Repetition {
+                node: Box::new(node),
+                quantifier,
+            };
+        }
+        nodes.push(node);
+
+        if self.with_right_boundary {
+            nodes.push(Node::Assertion(Assertion::WordBoundary));
+        }
+        if self.with_right_non_boundary {
+            nodes.push(Node::Assertion(Assertion::NonWordBoundary));
+        }
+        if let Err(error) = self.validate(method) {
+            nodes.push(Node::Invalid(error));
+        }
+        nodes
+    }
+
+    /// Checks for empty or reversed ranges and conflicting quantifiers.
+    pub(crate) fn validate(&self, method: &'static str) -> Result<(), EasyRegexError> {
+        if let Some((None, None)) = self.range {
+            return Err(EasyRegexError::EmptyRange { method });
+        }
+
+        let quantifiers = self.quantifiers();
+        for quantifier in &quantifiers {
+            if let Quantifier::Range(from, to) | Quantifier::RangeUngreedy(from, to) = *quantifier {
+                if from > to {
+                    return Err(EasyRegexError::InvalidRange { method, from, to });
+                }
+            }
+        }
+        if quantifiers.len() > 1 {
+            return Err(EasyRegexError::ConflictingQuantifiers {
+                method,
+                quantifiers,
+            });
+        }
+        Ok(())
+    }
+
+    /// Lists the quantifiers in the order they are appended to an expression.
+    ///
+    /// An ```is_optional``` following another quantifier turns it ungreedy, e.g. ```+?```.
+    fn quantifiers(&self) -> Vec<Quantifier> {
+        let mut quantifiers = Vec::new();
+
+        if let Some(quantifier) = self
+            .range
+            .and_then(|(from, to)| Quantifier::from_range(from, to))
+        {
+            quantifiers.push(quantifier);
+        }
+        if let Some(number) = self.exactly {
+            quantifiers.push(Quantifier::Exactly(number));
+        }
+        if self.is_nil_or_more {
+            quantifiers.push(Quantifier::NilOrMore);
+        }
+        if self.is_one_or_more {
+            quantifiers.push(Quantifier::OneOrMore);
+        }
+        if self.is_optional {
+            match quantifiers.pop() {
+                Some(last) => quantifiers.push(last.ungreedy()),
+                None => quantifiers.push(Quantifier::Optional),
+            }
+        }
+        if self.is_optional_ungreedy {
+            quantifiers.push(Quantifier::OptionalUngreedy);
+        }
+        quantifiers.extend(self.quantifier);
+        quantifiers
+    }
+}
+
+impl From<Quantifier> for Settings {
+    fn from(quantifier: Quantifier) -> Self {
+        Settings {
+            quantifier: Some(quantifier),
             ..Default::default()
         }
     }
 }
 
 /// A set of meta extending ```Settings``` struct by adding a property to determine if a group is capturing or not.
+#[derive(Default)]
 pub struct GroupSettings {
     pub other: Settings,
     pub is_non_capture: bool,
 }
 
-impl Default for GroupSettings {
-    fn default() -> Self {
+impl From<Quantifier> for GroupSettings {
+    fn from(quantifier: Quantifier) -> Self {
         GroupSettings {
-            other: Settings::default(),
-            is_non_capture: false,
+            other: Settings::from(quantifier),
+            ..Default::default()
         }
     }
 }
 
 impl GroupSettings {
-    pub fn grp_exactly(number: u8) -> Self {
+    pub fn grp_exactly(number: u32) -> Self {
         GroupSettings {
             other: Settings {
                 exactly: Some(number),
@@ -122,7 +597,7 @@ impl GroupSettings {
         }
     }
 
-    pub fn grp_range(from: Option<u8>, to: Option<u8>) -> Self {
+    pub fn grp_range(from: Option<u32>, to: Option<u32>) -> Self {
         GroupSettings {
             other: Settings {
                 range: Some((from, to)),
@@ -131,4 +606,73 @@ impl GroupSettings {
             ..Default::default()
         }
     }
+
+    /// Ungreedy variant of ```grp_exactly``` i.e. ```{n}?```.
+    pub fn grp_exactly_ungreedy(number: u32) -> Self {
+        GroupSettings {
+            other: Settings::exactly_ungreedy(number),
+            ..Default::default()
+        }
+    }
+
+    /// Ungreedy variant of ```grp_range``` e.g. ```{n,m}?```.
+    pub fn grp_range_ungreedy(from: Option<u32>, to: Option<u32>) -> Self {
+        GroupSettings {
+            other: Settings::range_ungreedy(from, to),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{settings::base::*, EasyRegex};
+
+    #[test]
+    fn quantifier_from_presets_works() {
+        assert_eq!(Ok(Quantifier::Optional), Quantifier::try_from(&*OPTIONAL));
+        assert_eq!(
+            Ok(Quantifier::NilOrMoreUngreedy),
+            Quantifier::try_from(&*NIL_OR_MORE_UNGREEDY)
+        );
+        assert_eq!(
+            Ok(Quantifier::AtMost(4)),
+            Quantifier::try_from(&Settings::range(None, Some(4)))
+        );
+    }
+
+    #[test]
+    fn flag_set_settings_works() {
+        let flags = FlagSet::new().case_insensitive(true).unicode(false) | Flags::SingleLine;
+        assert_eq!("?i-mu", flags.to_string());
+        assert!(flags.contains(Flags::Insensitive));
+        assert!(!flags.contains(Flags::Multiline));
+        let flags = (flags | FlagSet::from(Flags::Multiline)).with(Flags::Sensitive);
+        assert_eq!("?m-iu", flags.to_string());
+
+        let result = EasyRegex::new_section()
+            .literal("a", &(Flags::Insensitive | Flags::DotMatchNewLine).into())
+            .group("b", &FlagSet::new().swap_greed(true).crlf(true).into())
+            .literal("c", &FlagSet::new().into());
+        assert_eq!("(?is)a((?UR)b)c", result.as_str());
+        assert!(result.get_regex().is_ok());
+    }
+
+    #[test]
+    fn quantifier_from_conflicting_settings_fails() {
+        let settings = Settings {
+            is_nil_or_more: true,
+            is_one_or_more: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            "Quantifier::try_from: conflicting quantifiers '*', '+' are set",
+            Quantifier::try_from(&settings).unwrap_err().to_string()
+        );
+        assert_eq!(
+            Err(EasyRegexError::MissingQuantifier),
+            Quantifier::try_from(&*DEFAULT)
+        );
+    }
 }
@@ -0,0 +1,358 @@
+//! Creates Unicode script and general category classes.
+//!
+//! Instead of hardcoding code point ranges (e.g. the ```CHINESE_ALPHABET``` constant which misses the CJK extensions),
+//! the [`unicode_script`](../struct.EasyRegex.html#method.unicode_script) and
+//! [`unicode_category`](../struct.EasyRegex.html#method.unicode_category) methods emit ```\p{...}``` classes
+//! which are kept up to date by the regex crate itself.
+//! Their negations, starting with ```non_```, emit ```\P{...}```.
+//! Scripts and other properties not listed by the ```Script``` and ```Category``` enums could be given by name to
+//! [`unicode_property`](../struct.EasyRegex.html#method.unicode_property), which checks it against the regex crate.
+
+use crate::{ast::Node, settings::Settings, EasyRegex, EasyRegexError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// Common Unicode scripts, used by the ```unicode_script``` method.
+///
+/// Other scripts known by the regex crate could be given by name to the ```unicode_property``` method.
+pub enum Script {
+    Arabic,
+    Armenian,
+    Bengali,
+    Common,
+    Cyrillic,
+    Devanagari,
+    Ethiopic,
+    Georgian,
+    Greek,
+    Gujarati,
+    Gurmukhi,
+    Han,
+    Hangul,
+    Hebrew,
+    Hiragana,
+    Inherited,
+    Kannada,
+    Katakana,
+    Khmer,
+    Lao,
+    Latin,
+    Malayalam,
+    Mongolian,
+    Myanmar,
+    Sinhala,
+    Syriac,
+    Tamil,
+    Telugu,
+    Thaana,
+    Thai,
+    Tibetan,
+}
+
+impl Script {
+    /// The property name of the script, e.g. ```Han```.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Script::Arabic => "Arabic",
+            Script::Armenian => "Armenian",
+            Script::Bengali => "Bengali",
+            Script::Common => "Common",
+            Script::Cyrillic => "Cyrillic",
+            Script::Devanagari => "Devanagari",
+            Script::Ethiopic => "Ethiopic",
+            Script::Georgian => "Georgian",
+            Script::Greek => "Greek",
+            Script::Gujarati => "Gujarati",
+            Script::Gurmukhi => "Gurmukhi",
+            Script::Han => "Han",
+            Script::Hangul => "Hangul",
+            Script::Hebrew => "Hebrew",
+            Script::Hiragana => "Hiragana",
+            Script::Inherited => "Inherited",
+            Script::Kannada => "Kannada",
+            Script::Katakana => "Katakana",
+            Script::Khmer => "Khmer",
+            Script::Lao => "Lao",
+            Script::Latin => "Latin",
+            Script::Malayalam => "Malayalam",
+            Script::Mongolian => "Mongolian",
+            Script::Myanmar => "Myanmar",
+            Script::Sinhala => "Sinhala",
+            Script::Syriac => "Syriac",
+            Script::Tamil => "Tamil",
+            Script::Telugu => "Telugu",
+            Script::Thaana => "Thaana",
+            Script::Thai => "Thai",
+            Script::Tibetan => "Tibetan",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Unicode general categories, used by the ```unicode_category``` method.
+pub enum Category {
+    Letter,
+    CasedLetter,
+    UppercaseLetter,
+    LowercaseLetter,
+    TitlecaseLetter,
+    ModifierLetter,
+    OtherLetter,
+    Mark,
+    NonspacingMark,
+    SpacingMark,
+    EnclosingMark,
+    Number,
+    DecimalNumber,
+    LetterNumber,
+    OtherNumber,
+    Punctuation,
+    ConnectorPunctuation,
+    DashPunctuation,
+    OpenPunctuation,
+    ClosePunctuation,
+    InitialPunctuation,
+    FinalPunctuation,
+    OtherPunctuation,
+    Symbol,
+    MathSymbol,
+    CurrencySymbol,
+    ModifierSymbol,
+    OtherSymbol,
+    Separator,
+    SpaceSeparator,
+    LineSeparator,
+    ParagraphSeparator,
+    Other,
+    Control,
+    Format,
+    PrivateUse,
+    Unassigned,
+}
+
+impl Category {
+    /// The property name of the category, e.g. ```Uppercase_Letter```.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::Letter => "Letter",
+            Category::CasedLetter => "Cased_Letter",
+            Category::UppercaseLetter => "Uppercase_Letter",
+            Category::LowercaseLetter => "Lowercase_Letter",
+            Category::TitlecaseLetter => "Titlecase_Letter",
+            Category::ModifierLetter => "Modifier_Letter",
+            Category::OtherLetter => "Other_Letter",
+            Category::Mark => "Mark",
+            Category::NonspacingMark => "Nonspacing_Mark",
+            Category::SpacingMark => "Spacing_Mark",
+            Category::EnclosingMark => "Enclosing_Mark",
+            Category::Number => "Number",
+            Category::DecimalNumber => "Decimal_Number",
+            Category::LetterNumber => "Letter_Number",
+            Category::OtherNumber => "Other_Number",
+            Category::Punctuation => "Punctuation",
+            Category::ConnectorPunctuation => "Connector_Punctuation",
+            Category::DashPunctuation => "Dash_Punctuation",
+            Category::OpenPunctuation => "Open_Punctuation",
+            Category::ClosePunctuation => "Close_Punctuation",
+            Category::InitialPunctuation => "Initial_Punctuation",
+            Category::FinalPunctuation => "Final_Punctuation",
+            Category::OtherPunctuation => "Other_Punctuation",
+            Category::Symbol => "Symbol",
+            Category::MathSymbol => "Math_Symbol",
+            Category::CurrencySymbol => "Currency_Symbol",
+            Category::ModifierSymbol => "Modifier_Symbol",
+            Category::OtherSymbol => "Other_Symbol",
+            Category::Separator => "Separator",
+            Category::SpaceSeparator => "Space_Separator",
+            Category::LineSeparator => "Line_Separator",
+            Category::ParagraphSeparator => "Paragraph_Separator",
+            Category::Other => "Other",
+            Category::Control => "Control",
+            Category::Format => "Format",
+            Category::PrivateUse => "Private_Use",
+            Category::Unassigned => "Unassigned",
+        }
+    }
+}
+
+impl EasyRegex {
+    /// Adds the ```\p{...}``` class of a Unicode script.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::base::ONE_OR_MORE, unicode::Script};
+    ///
+    /// let result = EasyRegex::new_section().unicode_script(Script::Han, &ONE_OR_MORE);
+    /// let regex = result.get_regex().unwrap();
+    /// assert_eq!(r"\p{Han}+", regex.as_str());
+    /// assert!(regex.is_match("𠀀")); // CJK Extension B
+    /// ```
+    pub fn unicode_script(self, script: Script, settings: &Settings) -> Self {
+        self.push(property_nodes("unicode_script", "p", script.as_str(), settings))
+    }
+
+    /// Adds the ```\P{...}``` class, matching any character not in a Unicode script.
+    pub fn non_unicode_script(self, script: Script, settings: &Settings) -> Self {
+        self.push(property_nodes("non_unicode_script", "P", script.as_str(), settings))
+    }
+
+    /// Adds the ```\p{...}``` class of a Unicode general category.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::base::DEFAULT, unicode::Category};
+    ///
+    /// let result = EasyRegex::new_section().unicode_category(Category::UppercaseLetter, &DEFAULT);
+    /// let regex = result.get_regex().unwrap();
+    /// assert_eq!(r"\p{Uppercase_Letter}", regex.as_str());
+    /// assert!(regex.is_match("Ж"));
+    /// ```
+    pub fn unicode_category(self, category: Category, settings: &Settings) -> Self {
+        self.push(property_nodes("unicode_category", "p", category.as_str(), settings))
+    }
+
+    /// Adds the ```\P{...}``` class, matching any character not in a Unicode general category.
+    pub fn non_unicode_category(self, category: Category, settings: &Settings) -> Self {
+        self.push(property_nodes("non_unicode_category", "P", category.as_str(), settings))
+    }
+
+    /// Adds the ```\p{...}``` class of any Unicode property known by the regex crate, given by its name.
+    ///
+    /// Names the regex crate doesn't know are reported as ```EasyRegexError::UnknownProperty```.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easy_regex::{EasyRegex, settings::base::ONE_OR_MORE};
+    ///
+    /// let result = EasyRegex::new_section().unicode_property("Cherokee", &ONE_OR_MORE);
+    /// let regex = result.get_regex().unwrap();
+    /// assert_eq!(r"\p{Cherokee}+", regex.as_str());
+    /// assert!(regex.is_match("ᏣᎳᎩ"));
+    /// ```
+    pub fn unicode_property(self, name: &str, settings: &Settings) -> Self {
+        self.push(property_nodes("unicode_property", "p", name, settings))
+    }
+
+    /// Adds the ```\P{...}``` class, matching any character not having a Unicode property given by its name.
+    pub fn non_unicode_property(self, name: &str, settings: &Settings) -> Self {
+        self.push(property_nodes("non_unicode_property", "P", name, settings))
+    }
+}
+
+fn property_nodes(method: &'static str, escape: &str, name: &str, settings: &Settings) -> Vec<Node> {
+    let mut nodes = settings.apply(method, property_node(method, escape, name));
+    if let Err(error) = validate_property(method, name) {
+        nodes.push(Node::Invalid(error));
+    }
+    nodes
+}
+
+/// Creates a ```\p{...}``` or ```\P{...}``` class.
+pub(crate) fn property_node(method: &'static str, escape: &str, name: &str) -> Node {
+    Node::Raw {
+        expression: format!("\\{}{{{}}}", escape, name),
+        method,
+    }
+}
+
+/// Checks if the regex crate knows the given property name.
+pub(crate) fn validate_property(method: &'static str, name: &str) -> Result<(), EasyRegexError> {
+    match regex_syntax::parse(&format!("\\p{{{}}}", name)) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(EasyRegexError::UnknownProperty {
+            method,
+            name: name.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::base::*;
+
+    #[test]
+    fn unicode_script_works() {
+        let result = EasyRegex::start_of_line()
+            .unicode_script(Script::Greek, &ONE_OR_MORE)
+            .non_unicode_script(Script::Greek, &OPTIONAL)
+            .end_of_line();
+        assert_eq!("^\\p{Greek}+\\P{Greek}?$", result.as_str());
+        assert!(result.get_regex().unwrap().is_match("αβγ!"));
+    }
+
+    #[test]
+    fn unicode_category_works() {
+        let result = EasyRegex::new_section()
+            .unicode_category(Category::CurrencySymbol, &DEFAULT)
+            .non_unicode_category(Category::Letter, &ONE_OR_MORE);
+        assert_eq!("\\p{Currency_Symbol}\\P{Letter}+", result.as_str());
+        assert!(result.get_regex().unwrap().is_match("€ 12"));
+    }
+
+    #[test]
+    fn all_properties_are_known() {
+        let scripts = [
+            Script::Arabic, Script::Armenian, Script::Bengali, Script::Common, Script::Cyrillic,
+            Script::Devanagari, Script::Ethiopic, Script::Georgian, Script::Greek, Script::Gujarati,
+            Script::Gurmukhi, Script::Han, Script::Hangul, Script::Hebrew, Script::Hiragana,
+            Script::Inherited, Script::Kannada, Script::Katakana, Script::Khmer, Script::Lao,
+            Script::Latin, Script::Malayalam, Script::Mongolian, Script::Myanmar, Script::Sinhala,
+            Script::Syriac, Script::Tamil, Script::Telugu, Script::Thaana, Script::Thai,
+            Script::Tibetan,
+        ];
+        for script in scripts {
+            assert!(validate_property("test", script.as_str()).is_ok(), "{:?}", script);
+        }
+
+        let categories = [
+            Category::Letter, Category::CasedLetter, Category::UppercaseLetter,
+            Category::LowercaseLetter, Category::TitlecaseLetter, Category::ModifierLetter,
+            Category::OtherLetter, Category::Mark, Category::NonspacingMark, Category::SpacingMark,
+            Category::EnclosingMark, Category::Number, Category::DecimalNumber,
+            Category::LetterNumber, Category::OtherNumber, Category::Punctuation,
+            Category::ConnectorPunctuation, Category::DashPunctuation, Category::OpenPunctuation,
+            Category::ClosePunctuation, Category::InitialPunctuation, Category::FinalPunctuation,
+            Category::OtherPunctuation, Category::Symbol, Category::MathSymbol,
+            Category::CurrencySymbol, Category::ModifierSymbol, Category::OtherSymbol,
+            Category::Separator, Category::SpaceSeparator, Category::LineSeparator,
+            Category::ParagraphSeparator, Category::Other, Category::Control, Category::Format,
+            Category::PrivateUse, Category::Unassigned,
+        ];
+        for category in categories {
+            assert!(validate_property("test", category.as_str()).is_ok(), "{:?}", category);
+        }
+    }
+
+    #[test]
+    fn unknown_property_fails() {
+        assert_eq!(
+            Err(EasyRegexError::UnknownProperty {
+                method: "test",
+                name: "Klingon".to_string()
+            }),
+            validate_property("test", "Klingon")
+        );
+
+        let result = EasyRegex::new_section().non_unicode_property("Klingon", &DEFAULT);
+        assert_eq!(
+            EasyRegexError::UnknownProperty {
+                method: "non_unicode_property",
+                name: "Klingon".to_string()
+            },
+            result.get_regex().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn unicode_property_works() {
+        let result = EasyRegex::new_section()
+            .unicode_property("Script=Runic", &ONE_OR_MORE)
+            .non_unicode_property("Runic", &DEFAULT);
+        assert_eq!("\\p{Script=Runic}+\\P{Runic}", result.as_str());
+        assert!(result.get_regex().unwrap().is_match("ᚠᚢ."));
+    }
+}
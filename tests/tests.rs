@@ -13,26 +13,70 @@ fn complicated_regex() {
 
     let section_two = EasyRegex::new_section()
         .list(r"a-zA-Z0-9-.", &ONE_OR_MORE)
-        .literal(r"\.", &DEFAULT)
-        .list(&UPPER_LOWER_CASE, &Settings::range(Some(2), Some(4)))
+        .literal(".", &DEFAULT)
+        .list(UPPER_LOWER_CASE, &Settings::range(Some(2), Some(4)))
         .into_group(&DEFAULT)
         .group(":[0-9]+", &OPTIONAL_GROUP)
         .literal(r"/", &OPTIONAL);
 
     let section_three = EasyRegex::new_section()
-        .literal(&ALPHA_NUMERIC, &DEFAULT)
-        .literal(r"-._?,'/\\+&amp;%$#=~", &DEFAULT)
+        .raw(ALPHA_NUMERIC, &DEFAULT)
+        .raw(r"-._?,'/\\+&amp;%$#=~", &DEFAULT)
         .into_list(&NIL_OR_MORE)
         .into_group(&DEFAULT);
 
-    let result = format!(
-        "{}{}{}",
-        section_one.get_regex().unwrap(),
-        section_two.get_regex().unwrap(),
-        section_three.get_regex().unwrap()
-    );
+    let result = section_one + section_two + section_three;
     const URL_REGEX: &str = r"^(http|https|ftp):[/]{2}([a-zA-Z0-9-.]+\.[a-zA-Z]{2,4})(:[0-9]+)?/?([a-zA-Z0-9-._?,'/\\+&amp;%$#=~]*)";
-    assert_eq!(result, URL_REGEX);
+    assert_eq!(result.as_str(), URL_REGEX);
+}
+
+#[test]
+fn composed_sections() {
+    let number = EasyRegex::new_section().digit(&ONE_OR_MORE);
+    let level = EasyRegex::insensitive()
+        .literal("info", &DEFAULT)
+        .or()
+        .literal("warn", &DEFAULT);
+
+    let mut result = EasyRegex::start_of_line().group_of(number.clone(), &DEFAULT_GROUP);
+    result += EasyRegex::new_section().literal(" ", &DEFAULT);
+    result += level;
+    result = result
+        .literal(" code=", &DEFAULT)
+        .group_of(number, &DEFAULT_GROUP)
+        .end_of_line();
+
+    assert_eq!(r"^(\d+) (?:(?i)info|warn) code=(\d+)$", result.as_str());
+
+    let regex = result.get_regex().unwrap();
+    let captures = regex.captures("12 WARN code=7").unwrap();
+    assert_eq!("12", &captures[1]);
+    assert_eq!("7", &captures[2]);
+    assert!(!regex.is_match("12 warn CODE=7"));
+}
+
+#[test]
+fn alternation_followed_by_section() {
+    let result = EasyRegex::new("a").or().raw("b", &DEFAULT) + EasyRegex::new("c");
+    assert_eq!("(?:a|b)c", result.as_str());
+    assert!(!result.get_regex().unwrap().is_match("a"));
+
+    let mut result = EasyRegex::insensitive().literal("x", &DEFAULT);
+    result += EasyRegex::new("y");
+    assert_eq!("(?:(?i)x)y", result.as_str());
+    assert!(!result.get_regex().unwrap().is_match("XY"));
+}
+
+#[test]
+fn flags_followed_by_section() {
+    let result = EasyRegex::insensitive() + EasyRegex::new("abc");
+    assert_eq!("(?i:abc)", result.as_str());
+    assert!(result.get_regex().unwrap().is_match("ABC"));
+
+    let mut result = EasyRegex::multiline();
+    result += EasyRegex::start_of_line().literal("a", &DEFAULT).end_of_line();
+    assert_eq!("(?m:^a$)", result.as_str());
+    assert!(result.get_regex().unwrap().is_match("x\na\ny"));
 }
 
 // \b(25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\.
@@ -44,11 +88,11 @@ fn capturing_ip_address() {
     let result = EasyRegex::new_section()
         .word_boundary()
         .group("25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?", &DEFAULT_GROUP)
-        .literal("\\.", &DEFAULT)
+        .literal(".", &DEFAULT)
         .group("25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?", &DEFAULT_GROUP)
-        .literal("\\.", &DEFAULT)
+        .literal(".", &DEFAULT)
         .group("25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?", &DEFAULT_GROUP)
-        .literal("\\.", &DEFAULT)
+        .literal(".", &DEFAULT)
         .group("25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?", &DEFAULT_GROUP)
         .word_boundary();
 
@@ -77,6 +121,20 @@ fn customized_regex_with_named_groups() {
     );
 }
 
+#[test]
+fn non_consuming_accessors() {
+    let result = EasyRegex::new_section()
+        .literal("v", &DEFAULT)
+        .digit(&ONE_OR_MORE)
+        .literal(".", &DEFAULT)
+        .digit(&ONE_OR_MORE);
+
+    assert_eq!(r"v\d+\.\d+", result.as_str());
+    assert!(result.to_regex().unwrap().is_match("v1.5"));
+    assert!(!result.to_regex().unwrap().is_match("v1x5"));
+    assert_eq!(r"v\d+\.\d+", result.into_string());
+}
+
 #[test]
 fn scape_chars() {
     let result = EasyRegex::insensitive()
@@ -93,3 +151,10 @@ fn scape_chars() {
     );
     assert_eq!("soap SoAp", found_text);
 }
+
+#[test]
+fn easy_regex_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<EasyRegex>();
+    assert_send_sync::<easy_regex::set::NamedRegexSet>();
+}